@@ -0,0 +1,105 @@
+use reqwest::Method;
+
+use super::types::{GeoPolygon, IndexStats, NewPolygon, Polygon, SatelliteImage, Soil};
+use crate::client::{url_with_segment, OpenWeatherClient};
+
+const AGRO_API_BASE_URL: &str = "https://api.agromonitoring.com/agro/1.0";
+
+/// A client for the Agro monitoring API.
+///
+/// Manages field polygons and retrieves soil conditions and satellite vegetation
+/// indices (NDVI, EVI) for them.
+///
+/// # Fields
+/// - `client`: The shared client that sends the requests, holding the Agro API key.
+pub struct AgroClient {
+    client: OpenWeatherClient,
+}
+
+impl AgroClient {
+    pub fn new(api_key: String) -> Self {
+        Self::with_client(OpenWeatherClient::new("standard".to_string(), api_key))
+    }
+
+    /// Creates a client that shares the connection pool and API key of `client`.
+    pub fn with_client(client: OpenWeatherClient) -> Self {
+        Self { client }
+    }
+
+    pub fn set_api_key(&mut self, api_key: String) {
+        self.client.set_api_key(api_key);
+    }
+
+    /// Registers a field polygon and returns it with its ID, centroid and area.
+    pub async fn create_polygon(&self, name: String, polygon: GeoPolygon) -> Result<Polygon, Box<dyn std::error::Error>> {
+        let body = NewPolygon {
+            name,
+            geo_json: polygon.into(),
+        };
+
+        let request = self.client.request(Method::POST, &format!("{}/polygons", AGRO_API_BASE_URL)).json(&body);
+        Ok(self.client.send(request).await?.json().await?)
+    }
+
+    pub async fn get_polygons(&self) -> Result<Vec<Polygon>, Box<dyn std::error::Error>> {
+        let request = self.client.request(Method::GET, &format!("{}/polygons", AGRO_API_BASE_URL));
+        Ok(self.client.send(request).await?.json().await?)
+    }
+
+    pub async fn get_polygon(&self, id: &str) -> Result<Polygon, Box<dyn std::error::Error>> {
+        let request = self.client.request(Method::GET, &polygon_url(id)?);
+        Ok(self.client.send(request).await?.json().await?)
+    }
+
+    pub async fn delete_polygon(&self, id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let request = self.client.request(Method::DELETE, &polygon_url(id)?);
+        self.client.send(request).await?;
+        Ok(())
+    }
+
+    /// Fetches the current soil temperature and moisture of a polygon.
+    pub async fn get_soil(&self, polygon_id: &str) -> Result<Soil, Box<dyn std::error::Error>> {
+        let request = self.client
+            .request(Method::GET, &format!("{}/soil", AGRO_API_BASE_URL))
+            .query(&[("polyid", polygon_id)]);
+        Ok(self.client.send(request).await?.json().await?)
+    }
+
+    /// Lists the satellite passes over a polygon between `start` and `end` (unix, UTC).
+    pub async fn search_images(&self, polygon_id: &str, start: i64, end: i64) -> Result<Vec<SatelliteImage>, Box<dyn std::error::Error>> {
+        if start > end {
+            return Err("Image search start must not be after end".into());
+        }
+
+        let request = self.client
+            .request(Method::GET, &format!("{}/image/search", AGRO_API_BASE_URL))
+            .query(&[
+                ("polyid", polygon_id.to_string()),
+                ("start", start.to_string()),
+                ("end", end.to_string())
+            ]);
+        Ok(self.client.send(request).await?.json().await?)
+    }
+
+    /// Fetches the NDVI statistics of a satellite pass.
+    pub async fn get_ndvi_stats(&self, image: &SatelliteImage) -> Result<IndexStats, Box<dyn std::error::Error>> {
+        let url = image.stats.ndvi.as_deref().ok_or("NDVI statistics are not available for this image")?;
+        self.get_index_stats(url).await
+    }
+
+    /// Fetches the EVI statistics of a satellite pass.
+    pub async fn get_evi_stats(&self, image: &SatelliteImage) -> Result<IndexStats, Box<dyn std::error::Error>> {
+        let url = image.stats.evi.as_deref().ok_or("EVI statistics are not available for this image")?;
+        self.get_index_stats(url).await
+    }
+
+    async fn get_index_stats(&self, url: &str) -> Result<IndexStats, Box<dyn std::error::Error>> {
+        // Statistics URLs returned by the API already carry the API key.
+        let request = self.client.request(Method::GET, url);
+        Ok(self.client.execute(request).await?.json().await?)
+    }
+}
+
+fn polygon_url(id: &str) -> Result<String, String> {
+    url_with_segment(&format!("{}/polygons", AGRO_API_BASE_URL), id)
+}
@@ -0,0 +1,366 @@
+use serde::{Deserialize, Serialize};
+
+use crate::weather::Coord;
+
+// region: GeoPolygon
+
+/// Represents a GeoJSON `Polygon` geometry with positions in `[lon, lat]` order.
+///
+/// Provides validation to ensure every position is a valid coordinate and that every
+/// ring is closed (first and last positions equal) with at least four positions. The same
+/// checks apply when deserializing.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "type": "Polygon",
+///     "coordinates": [
+///         [
+///             [-82.2010, 42.4410],
+///             [-82.1970, 42.4410],
+///             [-82.1970, 42.4440],
+///             [-82.2010, 42.4440],
+///             [-82.2010, 42.4410]
+///         ]
+///     ]
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "GeoJsonPolygon", into = "GeoJsonPolygon")]
+pub struct GeoPolygon {
+    /// Exterior ring followed by optional holes
+    rings: Vec<Vec<Coord>>,
+}
+
+impl GeoPolygon {
+    pub fn new(rings: Vec<Vec<Coord>>) -> Result<Self, String> {
+        if rings.is_empty() {
+            return Err("Polygon must have an exterior ring".to_string());
+        }
+        for ring in &rings {
+            if ring.len() < 4 {
+                return Err("Polygon rings must have at least 4 positions".to_string());
+            }
+            if ring.first() != ring.last() {
+                return Err("Polygon rings must be closed (first and last positions equal)".to_string());
+            }
+        }
+
+        Ok(GeoPolygon { rings })
+    }
+
+    /// Builds a polygon without holes from its outline, closing the ring if needed.
+    pub fn from_coords(outline: &[Coord]) -> Result<Self, String> {
+        let mut ring = outline.to_vec();
+        if ring.first() != ring.last() {
+            if let Some(first) = ring.first().cloned() {
+                ring.push(first);
+            }
+        }
+
+        GeoPolygon::new(vec![ring])
+    }
+
+    /// Exterior ring followed by optional holes, each closed.
+    pub fn rings(&self) -> &[Vec<Coord>] {
+        &self.rings
+    }
+}
+
+/// GeoJSON wire form of [`GeoPolygon`], validated through [`GeoPolygon::new`] when read.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename = "Polygon")]
+struct GeoJsonPolygon {
+    coordinates: Vec<Vec<[f64; 2]>>,
+}
+
+impl TryFrom<GeoJsonPolygon> for GeoPolygon {
+    type Error = String;
+
+    fn try_from(polygon: GeoJsonPolygon) -> Result<Self, Self::Error> {
+        let rings = polygon.coordinates
+            .into_iter()
+            .map(|ring| ring.into_iter().map(|[lon, lat]| Coord::new(lon, lat)).collect())
+            .collect::<Result<_, _>>()?;
+        GeoPolygon::new(rings)
+    }
+}
+
+impl From<GeoPolygon> for GeoJsonPolygon {
+    fn from(polygon: GeoPolygon) -> Self {
+        GeoJsonPolygon {
+            coordinates: polygon.rings
+                .into_iter()
+                .map(|ring| ring.into_iter().map(|coord| [coord.lon, coord.lat]).collect())
+                .collect(),
+        }
+    }
+}
+
+/// Represents a GeoJSON `Feature` wrapping a polygon, as expected by the Agro API.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename = "Feature")]
+pub struct GeoFeature {
+    /// Free-form feature properties
+    #[serde(default)]
+    pub properties: serde_json::Map<String, serde_json::Value>,
+    /// Polygon geometry
+    pub geometry: GeoPolygon,
+}
+
+impl From<GeoPolygon> for GeoFeature {
+    fn from(geometry: GeoPolygon) -> Self {
+        GeoFeature {
+            properties: serde_json::Map::new(),
+            geometry,
+        }
+    }
+}
+
+// endregion
+
+// region: Polygon
+
+/// Represents the body used to register a new field polygon.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NewPolygon {
+    /// Polygon name
+    pub name: String,
+    /// Polygon outline
+    pub geo_json: GeoFeature,
+}
+
+/// Represents a field polygon registered with the Agro API.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "id": "5aaa8052cbbbb5000b73ff66",
+///     "geo_json": {
+///         "type": "Feature",
+///         "properties": {},
+///         "geometry": {
+///             "type": "Polygon",
+///             "coordinates": [[[-82.2010, 42.4410], [-82.1970, 42.4410], [-82.1970, 42.4440], [-82.2010, 42.4410]]]
+///         }
+///     },
+///     "name": "North field",
+///     "center": [-82.1983, 42.4420],
+///     "area": 11.06,
+///     "user_id": "5a5f5e4b4d0c5a0001ac5e65"
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Polygon {
+    /// Polygon ID
+    pub id: String,
+    /// Polygon outline
+    pub geo_json: GeoFeature,
+    /// Polygon name
+    pub name: String,
+    /// Centroid (`[lon, lat]` in the JSON)
+    #[serde(with = "crate::geo::types::lon_lat_array")]
+    pub center: Coord,
+    /// Area, hectares
+    pub area: f64,
+    /// Owner account ID
+    pub user_id: Option<String>,
+}
+
+// endregion
+
+// region: Soil
+
+/// Represents the current soil conditions of a polygon.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "dt": 1752449935,
+///     "t10": 291.54,
+///     "moisture": 0.175,
+///     "t0": 295.1
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Soil {
+    /// Time of data calculation, unix, UTC
+    pub dt: i64,
+    /// Temperature 10 cm below the surface, Kelvin
+    pub t10: f64,
+    /// Soil moisture, m³/m³
+    pub moisture: f64,
+    /// Surface temperature, Kelvin
+    pub t0: f64,
+}
+
+// endregion
+
+// region: SatelliteImage
+
+/// Represents the position of the sun when a satellite image was taken.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SunPosition {
+    /// Sun azimuth, degrees
+    pub azimuth: f64,
+    /// Sun elevation, degrees
+    pub elevation: f64,
+}
+
+/// Represents the URLs of the images or statistics available for a satellite pass.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ImageryUrls {
+    /// True color image (optional)
+    pub truecolor: Option<String>,
+    /// False color image (optional)
+    pub falsecolor: Option<String>,
+    /// NDVI (normalized difference vegetation index)
+    pub ndvi: Option<String>,
+    /// EVI (enhanced vegetation index)
+    pub evi: Option<String>,
+}
+
+/// Represents the metadata of a satellite pass over a polygon.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "dt": 1752400000,
+///     "type": "Sentinel-2",
+///     "dc": 100,
+///     "cl": 1.52,
+///     "sun": { "azimuth": 147.2, "elevation": 63.8 },
+///     "image": { "truecolor": "...", "falsecolor": "...", "ndvi": "...", "evi": "..." },
+///     "stats": { "ndvi": "...", "evi": "..." }
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SatelliteImage {
+    /// Time of the pass, unix, UTC
+    pub dt: i64,
+    /// Satellite name
+    #[serde(rename = "type")]
+    pub satellite: String,
+    /// Coverage of the polygon by the image, %
+    pub dc: f64,
+    /// Cloud coverage of the polygon, %
+    pub cl: f64,
+    /// Position of the sun during the pass
+    pub sun: SunPosition,
+    /// Image URLs
+    pub image: ImageryUrls,
+    /// Statistics URLs
+    pub stats: ImageryUrls,
+}
+
+/// Represents the distribution of a vegetation index over a polygon.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "std": 0.104,
+///     "p25": 0.532,
+///     "num": 57687,
+///     "min": 0.021,
+///     "max": 0.861,
+///     "median": 0.612,
+///     "p75": 0.681,
+///     "mean": 0.597
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndexStats {
+    /// Standard deviation
+    pub std: f64,
+    /// 25th percentile
+    pub p25: f64,
+    /// Number of pixels
+    pub num: i64,
+    /// Minimum value
+    pub min: f64,
+    /// Maximum value
+    pub max: f64,
+    /// Median value
+    pub median: f64,
+    /// 75th percentile
+    pub p75: f64,
+    /// Mean value
+    pub mean: f64,
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Vec<Coord> {
+        [(-82.2010, 42.4410), (-82.1970, 42.4410), (-82.1970, 42.4440), (-82.2010, 42.4440)]
+            .iter()
+            .map(|&(lon, lat)| Coord::new(lon, lat).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn from_coords_closes_the_ring() {
+        let polygon = GeoPolygon::from_coords(&square()).unwrap();
+        let ring = &polygon.rings()[0];
+        assert_eq!(ring.len(), 5);
+        assert_eq!(ring.first(), ring.last());
+
+        let mut closed = square();
+        closed.push(closed[0].clone());
+        assert_eq!(GeoPolygon::from_coords(&closed).unwrap(), polygon);
+    }
+
+    #[test]
+    fn rings_need_four_positions_and_must_be_closed() {
+        assert!(GeoPolygon::from_coords(&square()[..2]).is_err());
+        assert!(GeoPolygon::from_coords(&[]).is_err());
+        assert!(GeoPolygon::new(vec![]).is_err());
+        assert!(GeoPolygon::new(vec![square()]).is_err());
+    }
+
+    #[test]
+    fn serializes_as_geojson() {
+        let polygon = GeoPolygon::from_coords(&square()).unwrap();
+        let json = serde_json::to_value(&polygon).unwrap();
+        assert_eq!(json["type"], "Polygon");
+        assert_eq!(json["coordinates"][0][0], serde_json::json!([-82.2010, 42.4410]));
+        assert_eq!(json["coordinates"][0].as_array().unwrap().len(), 5);
+
+        let feature = serde_json::to_value(GeoFeature::from(polygon.clone())).unwrap();
+        assert_eq!(feature["type"], "Feature");
+        assert_eq!(feature["geometry"], json);
+
+        assert_eq!(serde_json::from_value::<GeoPolygon>(json).unwrap(), polygon);
+    }
+
+    #[test]
+    fn deserialization_validates_the_polygon() {
+        let open = r#"{"type":"Polygon","coordinates":[[[0,0],[1,0],[1,1],[0,1]]]}"#;
+        let short = r#"{"type":"Polygon","coordinates":[[[0,0],[1,0],[0,0]]]}"#;
+        let out_of_range = r#"{"type":"Polygon","coordinates":[[[0,0],[181,0],[1,1],[0,0]]]}"#;
+        assert!(serde_json::from_str::<GeoPolygon>(open).unwrap_err().to_string().contains("closed"));
+        assert!(serde_json::from_str::<GeoPolygon>(short).unwrap_err().to_string().contains("4 positions"));
+        assert!(serde_json::from_str::<GeoPolygon>(out_of_range).unwrap_err().to_string().contains("Longitude"));
+    }
+
+    #[test]
+    fn polygon_center_is_a_lon_lat_array() {
+        let json = r#"{
+            "id": "5aaa8052cbbbb5000b73ff66",
+            "geo_json": {
+                "type": "Feature",
+                "properties": {},
+                "geometry": {"type": "Polygon", "coordinates": [[[-82.2010, 42.4410], [-82.1970, 42.4410], [-82.1970, 42.4440], [-82.2010, 42.4410]]]}
+            },
+            "name": "North field",
+            "center": [-82.1983, 42.4420],
+            "area": 11.06
+        }"#;
+        let polygon: Polygon = serde_json::from_str(json).unwrap();
+        assert_eq!(polygon.center, Coord::new(-82.1983, 42.4420).unwrap());
+        assert_eq!(serde_json::to_value(&polygon).unwrap()["center"], serde_json::json!([-82.1983, 42.4420]));
+    }
+}
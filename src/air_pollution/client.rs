@@ -0,0 +1,52 @@
+use super::types::AirPollution;
+use crate::client::OpenWeatherClient;
+use crate::geo::Coord;
+
+/// A client for the current, forecast (4 days) and historical air pollution of a location.
+///
+/// This is a thin wrapper around [`OpenWeatherClient`] bound to one set of coordinates.
+///
+/// # Fields
+/// - `client`: The shared client that sends the requests.
+/// - `coord`: Coordinates the air pollution is requested for.
+pub struct AirPollutionClient {
+    client: OpenWeatherClient,
+    coord: Coord,
+}
+
+impl AirPollutionClient {
+    pub fn new(coord: impl Into<Coord>, api_key: String) -> Self {
+        Self::with_client(coord, OpenWeatherClient::new("standard".to_string(), api_key))
+    }
+
+    /// Creates a client that shares the connection pool and API key of `client`.
+    pub fn with_client(coord: impl Into<Coord>, client: OpenWeatherClient) -> Self {
+        Self {
+            client,
+            coord: coord.into(),
+        }
+    }
+
+    pub fn set_coord(&mut self, coord: impl Into<Coord>) {
+        self.coord = coord.into();
+    }
+
+    pub fn get_coord(&self) -> Coord {
+        self.coord.clone()
+    }
+
+    /// Fetches the current air pollution.
+    pub async fn get_current(&self) -> Result<AirPollution, Box<dyn std::error::Error>> {
+        self.client.air_pollution(&self.coord).await
+    }
+
+    /// Fetches the hourly air pollution forecast for the next 4 days.
+    pub async fn get_forecast(&self) -> Result<AirPollution, Box<dyn std::error::Error>> {
+        self.client.air_pollution_forecast(&self.coord).await
+    }
+
+    /// Fetches hourly historical air pollution between `start` and `end` (unix, UTC).
+    pub async fn get_history(&self, start: i64, end: i64) -> Result<AirPollution, Box<dyn std::error::Error>> {
+        self.client.air_pollution_history(&self.coord, start, end).await
+    }
+}
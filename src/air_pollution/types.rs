@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::geo::Coord;
+use crate::scale::numeric_scale;
+
+// region: AirQualityIndex
+
+/// Represents the OpenWeatherMap Air Quality Index, from best to worst.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum AirQualityIndex {
+    Good,
+    Fair,
+    Moderate,
+    Poor,
+    VeryPoor,
+    /// Index not known to this library
+    Unknown(u8),
+}
+
+numeric_scale!(AirQualityIndex {
+    1 => Good,
+    2 => Fair,
+    3 => Moderate,
+    4 => Poor,
+    5 => VeryPoor,
+});
+
+impl fmt::Display for AirQualityIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            AirQualityIndex::Good => "Good",
+            AirQualityIndex::Fair => "Fair",
+            AirQualityIndex::Moderate => "Moderate",
+            AirQualityIndex::Poor => "Poor",
+            AirQualityIndex::VeryPoor => "Very poor",
+            AirQualityIndex::Unknown(value) => return write!(f, "Unknown ({})", value),
+        };
+        write!(f, "{}", description)
+    }
+}
+
+// endregion
+
+// region: AirPollution
+
+/// Represents the Air Quality Index value.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AirPollutionMain {
+    /// Air Quality Index
+    pub aqi: AirQualityIndex,
+}
+
+/// Represents pollutant concentrations, μg/m³.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AirComponents {
+    /// Carbon monoxide
+    pub co: f64,
+    /// Nitrogen monoxide
+    pub no: f64,
+    /// Nitrogen dioxide
+    pub no2: f64,
+    /// Ozone
+    pub o3: f64,
+    /// Sulphur dioxide
+    pub so2: f64,
+    /// Fine particulate matter (diameter up to 2.5 μm)
+    pub pm2_5: f64,
+    /// Coarse particulate matter (diameter up to 10 μm)
+    pub pm10: f64,
+    /// Ammonia
+    pub nh3: f64,
+}
+
+/// Represents the air pollution at one timestamp.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "main": {
+///         "aqi": 2
+///     },
+///     "components": {
+///         "co": 201.94,
+///         "no": 0.02,
+///         "no2": 0.77,
+///         "o3": 68.66,
+///         "so2": 0.64,
+///         "pm2_5": 0.5,
+///         "pm10": 0.54,
+///         "nh3": 0.12
+///     },
+///     "dt": 1752449935
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AirPollutionItem {
+    /// Air Quality Index
+    pub main: AirPollutionMain,
+    /// Pollutant concentrations
+    pub components: AirComponents,
+    /// Time of the data, unix, UTC
+    pub dt: i64,
+}
+
+/// Represents the Air Pollution API response (current, forecast or historical).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AirPollution {
+    /// Geographic coordinates of the location
+    pub coord: Coord,
+    /// Air pollution for each timestamp
+    pub list: Vec<AirPollutionItem>,
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indices_order_by_value() {
+        assert!(AirQualityIndex::Good < AirQualityIndex::VeryPoor);
+        assert!(AirQualityIndex::Unknown(0) < AirQualityIndex::Good);
+        assert!(AirQualityIndex::Unknown(4) < AirQualityIndex::VeryPoor);
+        let readings = [AirQualityIndex::Unknown(0), AirQualityIndex::VeryPoor, AirQualityIndex::Fair];
+        assert_eq!(readings.iter().max(), Some(&AirQualityIndex::VeryPoor));
+    }
+}
@@ -0,0 +1,23 @@
+//! Offline astronomy calculations for any coordinate and date.
+//!
+//! Nothing in this module performs network calls. Times are Unix timestamps (UTC),
+//! matching the representation used by the API responses.
+
+pub mod moon;
+pub mod sun;
+
+pub use moon::{MoonDay, MoonIllumination, MoonPhaseName};
+pub use sun::{SolarPosition, SunTimes};
+
+pub(crate) const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Julian centuries since J2000.0 for a Unix timestamp.
+pub(crate) fn julian_century(timestamp: i64) -> f64 {
+    let julian_day = timestamp as f64 / SECONDS_PER_DAY as f64 + 2_440_587.5;
+    (julian_day - 2_451_545.0) / 36_525.0
+}
+
+/// Normalizes an angle in degrees to the range [0, 360).
+pub(crate) fn normalize_degrees(degrees: f64) -> f64 {
+    degrees.rem_euclid(360.0)
+}
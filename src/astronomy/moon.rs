@@ -0,0 +1,410 @@
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use std::fmt;
+
+use super::{normalize_degrees, SECONDS_PER_DAY};
+use crate::calendar::{checked_days_from_civil, civil_from_days, days_from_civil};
+use crate::weather::Coord;
+
+/// Obliquity of the ecliptic at J2000.0, degrees.
+const OBLIQUITY: f64 = 23.4397;
+
+/// Mean Earth-Sun distance, km.
+const SUN_DISTANCE: f64 = 149_598_000.0;
+
+/// Equatorial radius of the Earth, km.
+const EARTH_RADIUS: f64 = 6_378.14;
+
+/// Interval between samples when searching for moonrise and moonset, seconds.
+const SEARCH_STEP: i64 = 3_600;
+
+/// Mean Earth-Moon distance, km.
+const MOON_DISTANCE: f64 = 385_000.56;
+
+/// Largest periodic terms of the moon's longitude and distance (Meeus, *Astronomical
+/// Algorithms*, table 47.A): multiples of D, M, M′ and F, then the longitude amplitude in
+/// degrees and the distance amplitude in km.
+const LONGITUDE_DISTANCE_TERMS: [([f64; 4], f64, f64); 13] = [
+    ([0.0, 0.0, 1.0, 0.0], 6.288_774, -20_905.355),
+    ([2.0, 0.0, -1.0, 0.0], 1.274_027, -3_699.111),
+    ([2.0, 0.0, 0.0, 0.0], 0.658_314, -2_955.968),
+    ([0.0, 0.0, 2.0, 0.0], 0.213_618, -569.925),
+    ([0.0, 1.0, 0.0, 0.0], -0.185_116, 48.888),
+    ([0.0, 0.0, 0.0, 2.0], -0.114_332, -3.149),
+    ([2.0, 0.0, -2.0, 0.0], 0.058_793, 246.158),
+    ([2.0, -1.0, -1.0, 0.0], 0.057_066, -152.138),
+    ([2.0, 0.0, 1.0, 0.0], 0.053_322, -170.733),
+    ([2.0, -1.0, 0.0, 0.0], 0.045_758, -204.586),
+    ([0.0, 1.0, -1.0, 0.0], -0.040_923, -129.620),
+    ([1.0, 0.0, 0.0, 0.0], -0.034_720, 108.743),
+    ([0.0, 1.0, 1.0, 0.0], -0.030_383, 104.755),
+];
+
+/// Largest periodic terms of the moon's latitude (Meeus, table 47.B): multiples of D, M, M′
+/// and F, then the amplitude in degrees.
+const LATITUDE_TERMS: [([f64; 4], f64); 7] = [
+    ([0.0, 0.0, 0.0, 1.0], 5.128_122),
+    ([0.0, 0.0, 1.0, 1.0], 0.280_602),
+    ([0.0, 0.0, 1.0, -1.0], 0.277_693),
+    ([2.0, 0.0, 0.0, -1.0], 0.173_237),
+    ([2.0, 0.0, -1.0, 1.0], 0.055_413),
+    ([2.0, 0.0, -1.0, -1.0], 0.046_271),
+    ([2.0, 0.0, 0.0, 1.0], 0.032_573),
+];
+
+// region: MoonPhaseName
+
+/// Represents the named phase of the lunar cycle.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MoonPhaseName {
+    NewMoon,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    FullMoon,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhaseName {
+    /// Returns the named phase for a phase fraction (0 and 1 new moon, 0.5 full moon).
+    ///
+    /// Each name covers one eighth of the cycle centered on its nominal value.
+    pub fn from_phase(phase: f64) -> Self {
+        match ((phase.rem_euclid(1.0) * 8.0).round() as u32) % 8 {
+            0 => MoonPhaseName::NewMoon,
+            1 => MoonPhaseName::WaxingCrescent,
+            2 => MoonPhaseName::FirstQuarter,
+            3 => MoonPhaseName::WaxingGibbous,
+            4 => MoonPhaseName::FullMoon,
+            5 => MoonPhaseName::WaningGibbous,
+            6 => MoonPhaseName::LastQuarter,
+            _ => MoonPhaseName::WaningCrescent,
+        }
+    }
+}
+
+impl fmt::Display for MoonPhaseName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            MoonPhaseName::NewMoon => "New Moon",
+            MoonPhaseName::WaxingCrescent => "Waxing Crescent",
+            MoonPhaseName::FirstQuarter => "First Quarter",
+            MoonPhaseName::WaxingGibbous => "Waxing Gibbous",
+            MoonPhaseName::FullMoon => "Full Moon",
+            MoonPhaseName::WaningGibbous => "Waning Gibbous",
+            MoonPhaseName::LastQuarter => "Last Quarter",
+            MoonPhaseName::WaningCrescent => "Waning Crescent",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// endregion
+
+// region: MoonIllumination
+
+/// Represents the illumination of the moon at a given moment.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct MoonIllumination {
+    /// Phase of the lunar cycle: 0 and 1 new moon, 0.25 first quarter, 0.5 full moon, 0.75 last quarter
+    pub phase: f64,
+    /// Illuminated fraction of the visible disc, from 0 to 1
+    pub fraction: f64,
+    /// Named phase
+    pub name: MoonPhaseName,
+}
+
+impl MoonIllumination {
+    /// Computes the illumination of the moon at `timestamp` (unix, UTC).
+    pub fn at(timestamp: i64) -> Self {
+        let days = days_since_j2000(timestamp);
+        let sun = sun_coordinates(days);
+        let moon = moon_coordinates(days);
+
+        let elongation = (sun.declination.sin() * moon.declination.sin()
+            + sun.declination.cos() * moon.declination.cos() * (sun.right_ascension - moon.right_ascension).cos())
+            .clamp(-1.0, 1.0)
+            .acos();
+        let incidence = (SUN_DISTANCE * elongation.sin()).atan2(moon.distance - SUN_DISTANCE * elongation.cos());
+        let angle = (sun.declination.cos() * (sun.right_ascension - moon.right_ascension).sin()).atan2(
+            sun.declination.sin() * moon.declination.cos()
+                - sun.declination.cos() * moon.declination.sin() * (sun.right_ascension - moon.right_ascension).cos(),
+        );
+
+        let sign = if angle < 0.0 { -1.0 } else { 1.0 };
+        let phase = (0.5 + 0.5 * incidence * sign / PI).rem_euclid(1.0);
+
+        MoonIllumination {
+            phase,
+            fraction: (1.0 + incidence.cos()) / 2.0,
+            name: MoonPhaseName::from_phase(phase),
+        }
+    }
+}
+
+// endregion
+
+// region: MoonDay
+
+/// Represents the moon data of one local day.
+///
+/// `moonrise`, `moonset` and `moon_phase` use the same representation as the One Call
+/// daily forecast: Unix timestamps (UTC), with `0` when the moon does not rise or set
+/// that day, and a phase fraction from 0 to 1.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MoonDay {
+    /// Moonrise time, unix, UTC.
+    ///
+    /// `0` means the moon does not rise that day; it is never a real moonrise time.
+    pub moonrise: i64,
+    /// Moonset time, unix, UTC.
+    ///
+    /// `0` means the moon does not set that day; it is never a real moonset time.
+    pub moonset: i64,
+    /// Moon phase at local noon: 0 and 1 new moon, 0.25 first quarter, 0.5 full moon, 0.75 last quarter
+    pub moon_phase: f64,
+    /// Illuminated fraction of the visible disc at local noon, from 0 to 1
+    pub illumination: f64,
+    /// Named phase at local noon
+    pub phase_name: MoonPhaseName,
+}
+
+impl MoonDay {
+    /// Computes the moon data of a calendar date at `coord`.
+    ///
+    /// The date is interpreted in local mean solar time at the coordinate's longitude.
+    pub fn for_date(coord: &Coord, year: i32, month: u32, day: u32) -> Result<Self, String> {
+        let days = checked_days_from_civil(year as i64, month as i64, day as i64)?;
+        let midnight = days * SECONDS_PER_DAY - (coord.lon / 360.0 * SECONDS_PER_DAY as f64).round() as i64;
+        Ok(Self::starting_at(coord, midnight))
+    }
+
+    /// Computes the moon data of the local day containing `timestamp` (unix, UTC), using
+    /// `timezone` (seconds from UTC) to determine the local day.
+    pub fn for_timestamp(coord: &Coord, timestamp: i64, timezone: i32) -> Self {
+        let local_days = (timestamp + timezone as i64).div_euclid(SECONDS_PER_DAY);
+        let (year, month, day) = civil_from_days(local_days);
+        let midnight = days_from_civil(year, month, day) * SECONDS_PER_DAY - timezone as i64;
+        Self::starting_at(coord, midnight)
+    }
+
+    fn starting_at(coord: &Coord, midnight: i64) -> Self {
+        let mut moonrise = 0;
+        let mut moonset = 0;
+
+        let mut previous = horizon_distance(coord, midnight);
+        let mut start = midnight;
+        while start < midnight + SECONDS_PER_DAY {
+            let end = start + SEARCH_STEP;
+            let current = horizon_distance(coord, end);
+
+            if previous < 0.0 && current >= 0.0 && moonrise == 0 {
+                moonrise = crossing(coord, start, end);
+            } else if previous >= 0.0 && current < 0.0 && moonset == 0 {
+                moonset = crossing(coord, start, end);
+            }
+
+            previous = current;
+            start = end;
+        }
+
+        let illumination = MoonIllumination::at(midnight + SECONDS_PER_DAY / 2);
+
+        MoonDay {
+            moonrise,
+            moonset,
+            moon_phase: illumination.phase,
+            illumination: illumination.fraction,
+            phase_name: illumination.name,
+        }
+    }
+}
+
+/// Finds the moment between `start` and `end` where the moon crosses the horizon by bisection.
+fn crossing(coord: &Coord, mut start: i64, mut end: i64) -> i64 {
+    let rising = horizon_distance(coord, start) < 0.0;
+    while end - start > 1 {
+        let middle = (start + end) / 2;
+        if (horizon_distance(coord, middle) < 0.0) == rising {
+            start = middle;
+        } else {
+            end = middle;
+        }
+    }
+    end
+}
+
+/// Angle in radians between the moon's upper limb and the apparent horizon.
+///
+/// Uses the standard rise/set altitude `0.7275 × parallax − 0.5667°`, which accounts for
+/// the moon's parallax, semi-diameter and average atmospheric refraction.
+fn horizon_distance(coord: &Coord, timestamp: i64) -> f64 {
+    let days = days_since_j2000(timestamp);
+    let moon = moon_coordinates(days);
+    let lat = coord.lat.to_radians();
+
+    let sidereal_time = normalize_degrees(280.460_618_37 + 360.985_647_366_29 * days + coord.lon).to_radians();
+    let hour_angle = sidereal_time - moon.right_ascension;
+    let altitude = (lat.sin() * moon.declination.sin() + lat.cos() * moon.declination.cos() * hour_angle.cos()).asin();
+
+    let parallax = (EARTH_RADIUS / moon.distance).asin();
+    altitude - (0.7275 * parallax - 0.5667_f64.to_radians())
+}
+
+// endregion
+
+// region: Coordinates
+
+/// Geocentric equatorial coordinates, radians and km.
+struct EquatorialCoordinates {
+    right_ascension: f64,
+    declination: f64,
+    distance: f64,
+}
+
+fn days_since_j2000(timestamp: i64) -> f64 {
+    timestamp as f64 / SECONDS_PER_DAY as f64 + 2_440_587.5 - 2_451_545.0
+}
+
+fn equatorial(longitude: f64, latitude: f64, distance: f64) -> EquatorialCoordinates {
+    let obliquity = OBLIQUITY.to_radians();
+    EquatorialCoordinates {
+        right_ascension: (longitude.sin() * obliquity.cos() - latitude.tan() * obliquity.sin()).atan2(longitude.cos()),
+        declination: (latitude.sin() * obliquity.cos() + latitude.cos() * obliquity.sin() * longitude.sin()).asin(),
+        distance,
+    }
+}
+
+/// Low precision position of the sun.
+fn sun_coordinates(days: f64) -> EquatorialCoordinates {
+    let anomaly = (357.5291 + 0.985_600_28 * days).to_radians();
+    let center = 1.9148 * anomaly.sin() + 0.02 * (2.0 * anomaly).sin() + 0.0003 * (3.0 * anomaly).sin();
+    let longitude = anomaly + (center + 102.9372 + 180.0).to_radians();
+
+    equatorial(longitude, 0.0, SUN_DISTANCE)
+}
+
+/// Position of the moon from the largest periodic terms of Meeus' lunar theory, good to
+/// about 0.02°, which puts moonrise and moonset within a minute.
+fn moon_coordinates(days: f64) -> EquatorialCoordinates {
+    let mean_longitude = 218.316_447_7 + 13.176_396_475 * days;
+    let arguments = [
+        // Mean elongation of the moon (D)
+        297.850_192_1 + 12.190_749_114 * days,
+        // Mean anomaly of the sun (M)
+        357.529_109_2 + 0.985_600_282 * days,
+        // Mean anomaly of the moon (M′)
+        134.963_396_4 + 13.064_992_950 * days,
+        // Argument of latitude of the moon (F)
+        93.272_095_0 + 13.229_350_240 * days,
+    ];
+    let angle = |multiples: &[f64; 4]| -> f64 {
+        multiples.iter().zip(arguments).map(|(multiple, argument)| multiple * argument).sum::<f64>().to_radians()
+    };
+
+    let mut longitude = mean_longitude;
+    let mut distance = MOON_DISTANCE;
+    for (multiples, longitude_amplitude, distance_amplitude) in &LONGITUDE_DISTANCE_TERMS {
+        let angle = angle(multiples);
+        longitude += longitude_amplitude * angle.sin();
+        distance += distance_amplitude * angle.cos();
+    }
+    let latitude: f64 = LATITUDE_TERMS.iter().map(|(multiples, amplitude)| amplitude * angle(multiples).sin()).sum();
+
+    equatorial(longitude.to_radians(), latitude.to_radians(), distance)
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NEW_MOON: i64 = 1_704_974_220; // 2024-01-11 11:57 UTC
+    const FIRST_QUARTER: i64 = 1_705_549_920; // 2024-01-18 03:52 UTC
+    const FULL_MOON: i64 = 1_706_205_240; // 2024-01-25 17:54 UTC
+    const LAST_QUARTER: i64 = 1_706_915_880; // 2024-02-02 23:18 UTC
+
+    fn london() -> Coord {
+        Coord::new(-0.1257, 51.5085).unwrap()
+    }
+
+    fn phase_distance(a: f64, b: f64) -> f64 {
+        let difference = (a - b).rem_euclid(1.0);
+        difference.min(1.0 - difference)
+    }
+
+    #[test]
+    fn phase_and_illumination_at_known_phases() {
+        let cases = [
+            (NEW_MOON, 0.0, 0.0, MoonPhaseName::NewMoon),
+            (FIRST_QUARTER, 0.25, 0.5, MoonPhaseName::FirstQuarter),
+            (FULL_MOON, 0.5, 1.0, MoonPhaseName::FullMoon),
+            (LAST_QUARTER, 0.75, 0.5, MoonPhaseName::LastQuarter),
+        ];
+        for (timestamp, phase, fraction, name) in cases {
+            let illumination = MoonIllumination::at(timestamp);
+            assert!(phase_distance(illumination.phase, phase) < 0.02, "{} phase {}", timestamp, illumination.phase);
+            assert!((illumination.fraction - fraction).abs() < 0.03, "{} fraction {}", timestamp, illumination.fraction);
+            assert_eq!(illumination.name, name);
+        }
+    }
+
+    fn chatham_kent() -> Coord {
+        Coord::new(-82.1993, 42.4421).unwrap()
+    }
+
+    fn sydney() -> Coord {
+        Coord::new(151.2093, -33.8688).unwrap()
+    }
+
+    /// Local midnight of a calendar date at a UTC offset of `timezone` seconds.
+    fn local_midnight(year: i64, month: i64, day: i64, timezone: i32) -> i64 {
+        days_from_civil(year, month, day) * SECONDS_PER_DAY - timezone as i64
+    }
+
+    /// Reference moonrise and moonset times from the full lunar series of Meeus,
+    /// *Astronomical Algorithms*, chapter 47 (upper limb on the horizon, standard refraction).
+    #[test]
+    fn rise_and_set_match_reference_times() {
+        let cases = [
+            // London, full moon: rises 15:59 GMT, sets 08:20 GMT
+            (london(), local_midnight(2024, 1, 25, 0), 0, 1_706_198_378, 1_706_170_830),
+            // London, new moon: rises 08:44 GMT, sets 15:45 GMT
+            (london(), local_midnight(2024, 1, 11, 0), 0, 1_704_962_698, 1_704_987_919),
+            // Chatham-Kent: rises 23:04 EDT, sets 08:49 EDT
+            (chatham_kent(), local_midnight(2025, 7, 13, -14_400), -14_400, 1_752_462_256, 1_752_410_997),
+            // Sydney: rises 14:20 AEST, sets 04:02 AEST
+            (sydney(), local_midnight(2024, 9, 15, 36_000), 36_000, 1_726_374_019, 1_726_336_972),
+        ];
+        for (coord, midnight, timezone, moonrise, moonset) in cases {
+            let moon = MoonDay::for_timestamp(&coord, midnight + SECONDS_PER_DAY / 2, timezone);
+            assert!((moon.moonrise - moonrise).abs() <= 3 * 60, "moonrise {} expected {}", moon.moonrise, moonrise);
+            assert!((moon.moonset - moonset).abs() <= 3 * 60, "moonset {} expected {}", moon.moonset, moonset);
+        }
+
+        assert_eq!(MoonDay::for_date(&london(), 2024, 1, 25).unwrap().phase_name, MoonPhaseName::FullMoon);
+    }
+
+    #[test]
+    fn days_without_moonrise_or_moonset_use_zero() {
+        // The moon rises after midnight on 2024-01-04 GMT and sets after midnight on 2024-01-18.
+        let no_rise = MoonDay::for_timestamp(&london(), local_midnight(2024, 1, 3, 0) + SECONDS_PER_DAY / 2, 0);
+        assert_eq!(no_rise.moonrise, 0);
+        assert!((no_rise.moonset - 1_704_281_211).abs() <= 3 * 60, "moonset {}", no_rise.moonset);
+
+        let no_set = MoonDay::for_timestamp(&london(), local_midnight(2024, 1, 17, 0) + SECONDS_PER_DAY / 2, 0);
+        assert_eq!(no_set.moonset, 0);
+        assert!((no_set.moonrise - 1_705_488_423).abs() <= 3 * 60, "moonrise {}", no_set.moonrise);
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        assert!(MoonDay::for_date(&london(), 2024, 2, 30).is_err());
+        assert!(MoonDay::for_date(&london(), 2023, 2, 29).is_err());
+        assert!(MoonDay::for_date(&london(), 2024, 13, 1).is_err());
+        assert!(MoonDay::for_date(&london(), 2024, 2, 29).is_ok());
+    }
+}
@@ -0,0 +1,248 @@
+use serde::{Deserialize, Serialize};
+
+use super::{julian_century, normalize_degrees, SECONDS_PER_DAY};
+use crate::calendar::checked_days_from_civil;
+use crate::weather::Coord;
+
+/// Sun altitude at sunrise/sunset: apparent radius plus average atmospheric refraction.
+const SUNRISE_ALTITUDE: f64 = -0.833;
+const CIVIL_TWILIGHT_ALTITUDE: f64 = -6.0;
+const NAUTICAL_TWILIGHT_ALTITUDE: f64 = -12.0;
+const ASTRONOMICAL_TWILIGHT_ALTITUDE: f64 = -18.0;
+
+// region: SolarPosition
+
+/// Represents the position of the sun in the sky as seen from a coordinate.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct SolarPosition {
+    /// Geometric altitude above the horizon in degrees (negative below the horizon, no refraction)
+    pub altitude: f64,
+    /// Azimuth in degrees, clockwise from north
+    pub azimuth: f64,
+}
+
+impl SolarPosition {
+    /// Computes the position of the sun at `timestamp` (unix, UTC).
+    pub fn at(coord: &Coord, timestamp: i64) -> Self {
+        let sun = SunParameters::at(timestamp);
+        let lat = coord.lat.to_radians();
+        let declination = sun.declination.to_radians();
+
+        let minutes = timestamp.rem_euclid(SECONDS_PER_DAY) as f64 / 60.0;
+        let true_solar_time = (minutes + sun.equation_of_time + 4.0 * coord.lon).rem_euclid(1440.0);
+        let hour_angle = (true_solar_time / 4.0 - 180.0).to_radians();
+
+        let altitude = (lat.sin() * declination.sin() + lat.cos() * declination.cos() * hour_angle.cos()).asin();
+        let azimuth = hour_angle.sin().atan2(hour_angle.cos() * lat.sin() - declination.tan() * lat.cos());
+
+        SolarPosition {
+            altitude: altitude.to_degrees(),
+            azimuth: normalize_degrees(azimuth.to_degrees() + 180.0),
+        }
+    }
+}
+
+// endregion
+
+// region: SunTimes
+
+/// Represents the times of solar events for one local day, as Unix timestamps (UTC).
+///
+/// Events that do not occur on that day (polar day or polar night) are `None`.
+/// `sunrise` and `sunset` use the same convention as [`crate::weather::Sys`], so they
+/// can be compared directly with the values returned by the API.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SunTimes {
+    /// Start of astronomical twilight (sun 18° below the horizon)
+    pub astronomical_dawn: Option<i64>,
+    /// Start of nautical twilight (sun 12° below the horizon)
+    pub nautical_dawn: Option<i64>,
+    /// Start of civil twilight (sun 6° below the horizon)
+    pub civil_dawn: Option<i64>,
+    /// Sunrise (upper limb on the horizon)
+    pub sunrise: Option<i64>,
+    /// Solar noon (sun crosses the meridian)
+    pub solar_noon: i64,
+    /// Sunset (upper limb on the horizon)
+    pub sunset: Option<i64>,
+    /// End of civil twilight
+    pub civil_dusk: Option<i64>,
+    /// End of nautical twilight
+    pub nautical_dusk: Option<i64>,
+    /// End of astronomical twilight
+    pub astronomical_dusk: Option<i64>,
+    /// Time between sunrise and sunset in seconds (a full day during polar day, zero during polar night)
+    pub day_length: i64,
+}
+
+impl SunTimes {
+    /// Computes the solar events of a calendar date at `coord`.
+    ///
+    /// The date is interpreted in local mean solar time at the coordinate's longitude, so the
+    /// events belong to the same local day regardless of the time zone.
+    pub fn for_date(coord: &Coord, year: i32, month: u32, day: u32) -> Result<Self, String> {
+        let days = checked_days_from_civil(year as i64, month as i64, day as i64)?;
+        let local_noon = days * SECONDS_PER_DAY + SECONDS_PER_DAY / 2 - (coord.lon / 360.0 * SECONDS_PER_DAY as f64).round() as i64;
+        Ok(Self::around(coord, local_noon))
+    }
+
+    /// Computes the solar events of the local day containing `timestamp` (unix, UTC), using
+    /// `timezone` (seconds from UTC) to determine the local date.
+    ///
+    /// Passing `WeatherResponse::dt` and `WeatherResponse::timezone` yields values comparable
+    /// with `WeatherResponse::sys`.
+    pub fn for_timestamp(coord: &Coord, timestamp: i64, timezone: i32) -> Self {
+        let local_days = (timestamp + timezone as i64).div_euclid(SECONDS_PER_DAY);
+        let local_noon = local_days * SECONDS_PER_DAY + SECONDS_PER_DAY / 2 - timezone as i64;
+        Self::around(coord, local_noon)
+    }
+
+    fn around(coord: &Coord, approximate_noon: i64) -> Self {
+        let solar_noon = refine(approximate_noon, |t| solar_noon_near(coord, t));
+
+        let event = |altitude: f64, rising: bool| -> Option<i64> {
+            let first = event_near(coord, solar_noon, solar_noon, altitude, rising)?;
+            event_near(coord, first, solar_noon, altitude, rising)
+        };
+
+        let sunrise = event(SUNRISE_ALTITUDE, true);
+        let sunset = event(SUNRISE_ALTITUDE, false);
+        let day_length = match (sunrise, sunset) {
+            (Some(rise), Some(set)) => set - rise,
+            _ if SolarPosition::at(coord, solar_noon).altitude > SUNRISE_ALTITUDE => SECONDS_PER_DAY,
+            _ => 0,
+        };
+
+        SunTimes {
+            astronomical_dawn: event(ASTRONOMICAL_TWILIGHT_ALTITUDE, true),
+            nautical_dawn: event(NAUTICAL_TWILIGHT_ALTITUDE, true),
+            civil_dawn: event(CIVIL_TWILIGHT_ALTITUDE, true),
+            sunrise,
+            solar_noon,
+            sunset,
+            civil_dusk: event(CIVIL_TWILIGHT_ALTITUDE, false),
+            nautical_dusk: event(NAUTICAL_TWILIGHT_ALTITUDE, false),
+            astronomical_dusk: event(ASTRONOMICAL_TWILIGHT_ALTITUDE, false),
+            day_length,
+        }
+    }
+}
+
+/// Applies `step` twice, feeding each estimate back in to account for the sun's motion.
+fn refine(estimate: i64, step: impl Fn(i64) -> i64) -> i64 {
+    step(step(estimate))
+}
+
+/// Solar noon of the day around `timestamp`, using the sun's parameters at `timestamp`.
+fn solar_noon_near(coord: &Coord, timestamp: i64) -> i64 {
+    let sun = SunParameters::at(timestamp);
+    let day_start = (timestamp + (coord.lon / 360.0 * SECONDS_PER_DAY as f64) as i64).div_euclid(SECONDS_PER_DAY) * SECONDS_PER_DAY;
+    let minutes = 720.0 - 4.0 * coord.lon - sun.equation_of_time;
+    day_start + (minutes * 60.0).round() as i64
+}
+
+/// Time the sun reaches `altitude` before (`rising`) or after solar noon, using the sun's
+/// parameters at `estimate`. Returns `None` if the sun never reaches that altitude.
+fn event_near(coord: &Coord, estimate: i64, solar_noon: i64, altitude: f64, rising: bool) -> Option<i64> {
+    let sun = SunParameters::at(estimate);
+    let lat = coord.lat.to_radians();
+    let declination = sun.declination.to_radians();
+
+    let cos_hour_angle = (altitude.to_radians().sin() - lat.sin() * declination.sin()) / (lat.cos() * declination.cos());
+    if !(-1.0..=1.0).contains(&cos_hour_angle) {
+        return None;
+    }
+
+    // Equation of time drift between noon and the event.
+    let noon_sun = SunParameters::at(solar_noon);
+    let drift = (noon_sun.equation_of_time - sun.equation_of_time) * 60.0;
+
+    let offset = cos_hour_angle.acos().to_degrees() * 4.0 * 60.0;
+    let time = if rising { solar_noon as f64 - offset } else { solar_noon as f64 + offset };
+    Some((time + drift).round() as i64)
+}
+
+// endregion
+
+// region: SunParameters
+
+/// Declination and equation of time of the sun (NOAA solar calculator formulas).
+struct SunParameters {
+    /// Declination in degrees
+    declination: f64,
+    /// Equation of time in minutes
+    equation_of_time: f64,
+}
+
+impl SunParameters {
+    fn at(timestamp: i64) -> Self {
+        let t = julian_century(timestamp);
+
+        let mean_longitude = normalize_degrees(280.46646 + t * (36000.76983 + t * 0.0003032));
+        let mean_anomaly = 357.52911 + t * (35999.05029 - 0.0001537 * t);
+        let eccentricity = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+
+        let m = mean_anomaly.to_radians();
+        let center = m.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+            + (2.0 * m).sin() * (0.019993 - 0.000101 * t)
+            + (3.0 * m).sin() * 0.000289;
+        let true_longitude = mean_longitude + center;
+
+        let omega = (125.04 - 1934.136 * t).to_radians();
+        let apparent_longitude = (true_longitude - 0.00569 - 0.00478 * omega.sin()).to_radians();
+
+        let mean_obliquity = 23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.0) / 60.0;
+        let obliquity = (mean_obliquity + 0.00256 * omega.cos()).to_radians();
+
+        let declination = (obliquity.sin() * apparent_longitude.sin()).asin().to_degrees();
+
+        let y = (obliquity / 2.0).tan().powi(2);
+        let l0 = mean_longitude.to_radians();
+        let equation_of_time = 4.0 * (y * (2.0 * l0).sin()
+            - 2.0 * eccentricity * m.sin()
+            + 4.0 * eccentricity * y * m.sin() * (2.0 * l0).cos()
+            - 0.5 * y * y * (4.0 * l0).sin()
+            - 1.25 * eccentricity * eccentricity * (2.0 * m).sin())
+            .to_degrees();
+
+        SunParameters { declination, equation_of_time }
+    }
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn london() -> Coord {
+        Coord::new(-0.1257, 51.5085).unwrap()
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        assert!(SunTimes::for_date(&london(), 2025, 2, 30).is_err());
+        assert!(SunTimes::for_date(&london(), 2023, 2, 29).is_err());
+        assert!(SunTimes::for_date(&london(), 2025, 4, 31).is_err());
+        assert!(SunTimes::for_date(&london(), 2025, 0, 1).is_err());
+        assert!(SunTimes::for_date(&london(), 2024, 2, 29).is_ok());
+    }
+
+    #[test]
+    fn for_date_matches_for_timestamp() {
+        let by_date = SunTimes::for_date(&london(), 2024, 6, 21).unwrap();
+        // 2024-06-21 12:00 BST
+        let by_timestamp = SunTimes::for_timestamp(&london(), 1_718_967_600, 3_600);
+        assert_eq!(by_date.sunrise, by_timestamp.sunrise);
+        assert_eq!(by_date.sunset, by_timestamp.sunset);
+    }
+
+    #[test]
+    fn sun_is_high_at_solar_noon_on_the_solstice() {
+        let sun = SunTimes::for_date(&london(), 2024, 6, 21).unwrap();
+        let position = SolarPosition::at(&london(), sun.solar_noon);
+        // 90° - 51.5° + 23.44°
+        assert!((position.altitude - 61.94).abs() < 0.1, "altitude {}", position.altitude);
+        assert!((position.azimuth - 180.0).abs() < 0.5, "azimuth {}", position.azimuth);
+    }
+}
@@ -0,0 +1,292 @@
+use std::future::Future;
+use std::time::Duration;
+
+use futures::lock::Mutex;
+use futures::stream::{self, StreamExt};
+use futures_timer::Delay;
+
+use super::types::{BatchError, BatchOptions, BatchResult, LocationWeather};
+use crate::client::OpenWeatherClient;
+use crate::geo::Coord;
+use crate::location::Location;
+use crate::query::WeatherQuery;
+use crate::weather::WeatherResponse;
+
+/// A client for fetching current weather for many locations at once.
+///
+/// All requests share a single HTTP connection pool. Locations closer to each other than
+/// [`BatchOptions::dedup_distance`] are fetched only once, requests
+/// run with bounded concurrency, and an optional rate limiter spaces out request starts.
+/// Failures are reported per location instead of aborting the whole batch.
+///
+/// # Fields
+/// - `client`: The client shared by every request in the batch (one connection pool).
+/// - `options`: Concurrency, rate limiting and deduplication settings.
+pub struct BatchWeatherClient {
+    client: OpenWeatherClient,
+    options: BatchOptions,
+}
+
+impl BatchWeatherClient {
+    pub fn new(units: String, api_key: String, options: BatchOptions) -> Self {
+        Self::with_client(OpenWeatherClient::new(units, api_key), options)
+    }
+
+    /// Creates a client that shares the connection pool, units and API key of `client`.
+    pub fn with_client(client: OpenWeatherClient, options: BatchOptions) -> Self {
+        Self {
+            client,
+            options,
+        }
+    }
+
+    pub fn set_options(&mut self, options: BatchOptions) {
+        self.options = options;
+    }
+
+    pub fn get_options(&self) -> BatchOptions {
+        self.options.clone()
+    }
+
+    pub async fn get_current_weather(&self, locations: Vec<Location>) -> BatchResult {
+        run_batch(locations, &self.options, |query| async move {
+            self.client.current(&query).await.map_err(BatchError::from)
+        }).await
+    }
+}
+
+/// Sends one `fetch` per group of nearby locations and gives every location the outcome of
+/// its group, in input order.
+async fn run_batch<F, Fut>(locations: Vec<Location>, options: &BatchOptions, fetch: F) -> BatchResult
+where
+    F: Fn(WeatherQuery) -> Fut,
+    Fut: Future<Output = Result<WeatherResponse, BatchError>>,
+{
+    let coords: Vec<&Coord> = locations.iter().map(|location| &location.coord).collect();
+    let groups = group_nearby(&coords, options.dedup_distance);
+
+    let limiter = options.min_request_interval.map(RateLimiter::new);
+    let limiter = limiter.as_ref();
+    let fetch = &fetch;
+
+    let mut outcomes: Vec<_> = stream::iter(groups.iter().enumerate())
+        .map(|(group, indices)| {
+            let query = WeatherQuery::from(&locations[indices[0]]);
+            async move {
+                if let Some(limiter) = limiter {
+                    limiter.acquire().await;
+                }
+
+                (group, fetch(query).await)
+            }
+        })
+        .buffer_unordered(options.concurrency.max(1))
+        .collect()
+        .await;
+    outcomes.sort_by_key(|(group, _)| *group);
+
+    let mut results: Vec<Option<LocationWeather>> = vec![None; locations.len()];
+    for ((_, weather), indices) in outcomes.into_iter().zip(&groups) {
+        for &index in indices {
+            results[index] = Some(LocationWeather {
+                location: locations[index].clone(),
+                weather: weather.clone(),
+            });
+        }
+    }
+
+    BatchResult {
+        results: results.into_iter().flatten().collect(),
+        requests_sent: groups.len(),
+    }
+}
+
+/// Groups indices of `coords` that lie within `distance` meters of the first coordinate of
+/// a group, keeping first-seen order. Each group is fetched with its first coordinate.
+fn group_nearby(coords: &[&Coord], distance: f64) -> Vec<Vec<usize>> {
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for (index, coord) in coords.iter().enumerate() {
+        let nearby = groups.iter_mut().find(|group| {
+            let first = coords[group[0]];
+            first == *coord || first.haversine_distance(coord) <= distance
+        });
+        match nearby {
+            Some(group) => group.push(index),
+            None => groups.push(vec![index]),
+        }
+    }
+    groups
+}
+
+/// Enforces a minimum delay between consecutive request starts.
+///
+/// Each start arms a timer for the next one, so a request only waits for whatever remains of
+/// the interval since the previous start. Waiters queue on the lock and start in turn.
+/// Uses only timers (no wall clock), so it behaves the same on native and WebAssembly targets.
+struct RateLimiter {
+    interval: Duration,
+    next_start: Mutex<Option<Delay>>,
+}
+
+impl RateLimiter {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next_start: Mutex::new(None),
+        }
+    }
+
+    async fn acquire(&self) {
+        let mut next_start = self.next_start.lock().await;
+        if let Some(delay) = next_start.take() {
+            delay.await;
+        }
+        *next_start = Some(Delay::new(self.interval));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ApiError;
+    use futures::executor::block_on;
+    use std::time::Instant;
+
+    fn coord(lon: f64, lat: f64) -> Coord {
+        Coord::new(lon, lat).unwrap()
+    }
+
+    #[test]
+    fn merges_points_across_a_rounding_boundary() {
+        // About 2 m apart, but 51.504999 and 51.505001 round to different 2-decimal cells.
+        let a = coord(-0.12, 51.504999);
+        let b = coord(-0.12, 51.505001);
+        assert_eq!(group_nearby(&[&a, &b], 1_000.0), vec![vec![0, 1]]);
+    }
+
+    #[test]
+    fn keeps_distant_points_apart_and_in_order() {
+        let london = coord(-0.1257, 51.5085);
+        let paris = coord(2.3488, 48.8534);
+        let westminster = coord(-0.1357, 51.4975);
+        assert_eq!(group_nearby(&[&london, &paris, &westminster], 2_000.0), vec![vec![0, 2], vec![1]]);
+    }
+
+    #[test]
+    fn zero_distance_only_merges_identical_points() {
+        let a = coord(10.0, 20.0);
+        let b = coord(10.0, 20.000001);
+        assert_eq!(group_nearby(&[&a, &b, &a], 0.0), vec![vec![0, 2], vec![1]]);
+    }
+
+    fn location(name: &str, lon: f64, lat: f64) -> Location {
+        Location { zip: String::new(), name: name.to_string(), coord: coord(lon, lat), country: "GB".to_string() }
+    }
+
+    fn weather_at(query: &WeatherQuery) -> WeatherResponse {
+        let WeatherQuery::Coord(coord) = query else { panic!("batch queries use coordinates") };
+        serde_json::from_value(serde_json::json!({
+            "coord": { "lon": coord.lon, "lat": coord.lat },
+            "weather": [],
+            "base": "stations",
+            "main": {},
+            "wind": { "speed": 0.0 },
+            "clouds": { "all": 0 },
+            "dt": 0,
+            "sys": {},
+            "timezone": 0,
+            "id": 0,
+            "name": "",
+            "cod": 200
+        }))
+        .unwrap()
+    }
+
+    fn options(concurrency: usize, min_request_interval: Option<Duration>) -> BatchOptions {
+        BatchOptions::new(concurrency, min_request_interval, 1_000.0).unwrap()
+    }
+
+    #[test]
+    fn results_follow_input_order_when_responses_arrive_out_of_order() {
+        let locations = vec![
+            location("London", -0.1257, 51.5085),
+            location("Paris", 2.3488, 48.8534),
+            location("Berlin", 13.4050, 52.5200),
+            location("Westminster", -0.1300, 51.5060),
+        ];
+        // Earlier requests take longer, so they complete last.
+        let result = block_on(run_batch(locations.clone(), &options(3, None), |query| async move {
+            let delay = match &query {
+                WeatherQuery::Coord(coord) if coord.lon < 0.0 => 60,
+                WeatherQuery::Coord(coord) if coord.lon < 10.0 => 30,
+                _ => 0,
+            };
+            Delay::new(Duration::from_millis(delay)).await;
+            Ok(weather_at(&query))
+        }));
+
+        assert_eq!(result.requests_sent, 3);
+        let names: Vec<&str> = result.results.iter().map(|r| r.location.name.as_str()).collect();
+        assert_eq!(names, ["London", "Paris", "Berlin", "Westminster"]);
+        for (result, expected) in result.results.iter().zip(["London", "Paris", "Berlin", "London"]) {
+            let fetched = &result.weather.as_ref().unwrap().coord;
+            let expected = &locations.iter().find(|l| l.name == expected).unwrap().coord;
+            assert_eq!(fetched, expected);
+        }
+    }
+
+    #[test]
+    fn failures_are_reported_per_location_and_keep_the_status() {
+        let locations = vec![
+            location("London", -0.1257, 51.5085),
+            location("Paris", 2.3488, 48.8534),
+            location("Westminster", -0.1300, 51.5060),
+        ];
+        let result = block_on(run_batch(locations, &options(2, None), |query| async move {
+            match &query {
+                WeatherQuery::Coord(coord) if coord.lon < 0.0 => {
+                    Err(BatchError::Api(ApiError { status: 429, message: Some("rate limited".to_string()) }))
+                }
+                _ => Ok(weather_at(&query)),
+            }
+        }));
+
+        assert!(!result.is_complete());
+        let succeeded: Vec<&str> = result.succeeded().map(|(location, _)| location.name.as_str()).collect();
+        assert_eq!(succeeded, ["Paris"]);
+
+        let failed: Vec<(&str, Option<u16>)> = result.failed().map(|(location, error)| (location.name.as_str(), error.status())).collect();
+        assert_eq!(failed, [("London", Some(429)), ("Westminster", Some(429))]);
+        assert!(result.results[0].weather.as_ref().unwrap_err().to_string().contains("rate limited"));
+    }
+
+    #[test]
+    fn boxed_errors_keep_the_api_status() {
+        let api: Box<dyn std::error::Error> = Box::new(ApiError { status: 404, message: None });
+        assert_eq!(BatchError::from(api).status(), Some(404));
+
+        let other: Box<dyn std::error::Error> = "connection reset".into();
+        let error = BatchError::from(other);
+        assert_eq!(error.status(), None);
+        assert_eq!(error.to_string(), "connection reset");
+    }
+
+    #[test]
+    fn rate_limit_counts_from_the_previous_start() {
+        let interval = Duration::from_millis(100);
+        let limiter = RateLimiter::new(interval);
+        let started = Instant::now();
+
+        block_on(async {
+            limiter.acquire().await;
+            // Work that takes most of the interval should not add to the wait.
+            Delay::new(Duration::from_millis(90)).await;
+            limiter.acquire().await;
+            limiter.acquire().await;
+        });
+
+        let elapsed = started.elapsed();
+        assert!(elapsed >= interval * 2, "{:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(270), "{:?}", elapsed);
+    }
+}
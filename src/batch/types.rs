@@ -0,0 +1,150 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use crate::error::ApiError;
+use crate::location::Location;
+use crate::weather::WeatherResponse;
+
+// region: BatchOptions
+
+/// Tuning parameters for a batch of weather requests.
+///
+/// # Fields
+/// - `concurrency`: Maximum number of requests in flight at the same time.
+/// - `min_request_interval`: Minimum delay between the start of two consecutive requests
+///   (e.g. `Duration::from_secs(1)` for 60 calls per minute). `None` disables rate limiting.
+/// - `dedup_distance`: Locations closer than this many meters (great-circle distance) are
+///   fetched once. `0.0` only merges identical coordinates.
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    /// Maximum number of concurrent requests
+    pub concurrency: usize,
+    /// Minimum delay between two consecutive requests (optional)
+    pub min_request_interval: Option<Duration>,
+    /// Distance in meters below which two locations share one request
+    pub dedup_distance: f64,
+}
+
+impl BatchOptions {
+    pub fn new(concurrency: usize, min_request_interval: Option<Duration>, dedup_distance: f64) -> Result<Self, String> {
+        if concurrency == 0 {
+            return Err("Concurrency must be at least 1".to_string());
+        }
+        if !dedup_distance.is_finite() || dedup_distance < 0.0 {
+            return Err("Deduplication distance must be a non-negative number of meters".to_string());
+        }
+
+        Ok(BatchOptions { concurrency, min_request_interval, dedup_distance })
+    }
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        BatchOptions {
+            concurrency: 8,
+            min_request_interval: None,
+            dedup_distance: 1_000.0,
+        }
+    }
+}
+
+// endregion
+
+// region: BatchError
+
+/// Represents why the request for one location in a batch failed.
+///
+/// Unlike the boxed errors returned by the other clients, it can be cloned and shared by
+/// every location merged into the same request.
+#[derive(Debug, Clone)]
+pub enum BatchError {
+    /// The API answered with an error status
+    Api(ApiError),
+    /// The request could not be sent or the response could not be read
+    Request(String),
+}
+
+impl BatchError {
+    /// HTTP status code, if the API answered with an error status.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            BatchError::Api(error) => Some(error.status),
+            BatchError::Request(_) => None,
+        }
+    }
+}
+
+impl From<Box<dyn Error>> for BatchError {
+    fn from(error: Box<dyn Error>) -> Self {
+        match error.downcast::<ApiError>() {
+            Ok(error) => BatchError::Api(*error),
+            Err(error) => BatchError::Request(error.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchError::Api(error) => write!(f, "{}", error),
+            BatchError::Request(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for BatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BatchError::Api(error) => Some(error),
+            BatchError::Request(_) => None,
+        }
+    }
+}
+
+// endregion
+
+// region: LocationWeather
+
+/// The outcome of the weather request for a single location in a batch.
+///
+/// Locations that were merged during deduplication share the same outcome.
+#[derive(Debug, Clone)]
+pub struct LocationWeather {
+    /// The location as it was passed to the batch
+    pub location: Location,
+    /// Weather for the location, or why the request failed
+    pub weather: Result<WeatherResponse, BatchError>,
+}
+
+// endregion
+
+// region: BatchResult
+
+/// Per-location results of a batch, in the same order as the input locations.
+#[derive(Debug, Clone)]
+pub struct BatchResult {
+    /// One entry per input location
+    pub results: Vec<LocationWeather>,
+    /// Number of HTTP requests actually sent after deduplication
+    pub requests_sent: usize,
+}
+
+impl BatchResult {
+    /// Returns the locations whose weather was fetched successfully.
+    pub fn succeeded(&self) -> impl Iterator<Item = (&Location, &WeatherResponse)> {
+        self.results.iter().filter_map(|r| r.weather.as_ref().ok().map(|w| (&r.location, w)))
+    }
+
+    /// Returns the locations whose request failed, together with the error.
+    pub fn failed(&self) -> impl Iterator<Item = (&Location, &BatchError)> {
+        self.results.iter().filter_map(|r| r.weather.as_ref().err().map(|e| (&r.location, e)))
+    }
+
+    /// Returns `true` when every location has weather data.
+    pub fn is_complete(&self) -> bool {
+        self.results.iter().all(|r| r.weather.is_ok())
+    }
+}
+
+// endregion
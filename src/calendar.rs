@@ -0,0 +1,52 @@
+//! Proleptic Gregorian calendar helpers shared by modules that exchange dates with the API.
+
+/// Number of days since 1970-01-01 for a proleptic Gregorian date.
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = (if year >= 0 { year } else { year - 399 }) / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Number of days since 1970-01-01 for a date, rejecting dates such as 2025-02-30 that
+/// [`days_from_civil`] would silently roll over into the next month.
+pub(crate) fn checked_days_from_civil(year: i64, month: i64, day: i64) -> Result<i64, String> {
+    let days = days_from_civil(year, month, day);
+    if civil_from_days(days) != (year, month, day) {
+        return Err(format!("Invalid date: {:04}-{:02}-{:02}", year, month, day));
+    }
+    Ok(days)
+}
+
+/// Proleptic Gregorian date (year, month, day) for a number of days since 1970-01-01.
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = (if days >= 0 { days } else { days - 146_096 }) / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Parses a `YYYY-MM-DD` date into days since 1970-01-01.
+pub(crate) fn parse_date(value: &str) -> Result<i64, String> {
+    let invalid = || format!("Invalid date (expected YYYY-MM-DD): {}", value);
+    let parts: Vec<i64> = value.split('-').map(str::parse).collect::<Result<_, _>>().map_err(|_| invalid())?;
+    if parts.len() != 3 {
+        return Err(invalid());
+    }
+
+    checked_days_from_civil(parts[0], parts[1], parts[2]).map_err(|_| invalid())
+}
+
+/// Formats days since 1970-01-01 as `YYYY-MM-DD`.
+pub(crate) fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
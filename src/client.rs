@@ -0,0 +1,322 @@
+use crate::air_pollution::AirPollution;
+use crate::error::ApiError;
+use crate::forecast::{DailyForecast, Forecast, HourlyForecast};
+use crate::geo::{BoundingBox, Coord};
+use crate::location::{Location, Place, PostalCode};
+use crate::logging::debug_log;
+use crate::query::WeatherQuery;
+use crate::units::Units;
+use crate::weather::{CityWeather, CityWeatherList, WeatherResponse};
+
+const WEATHER_API_BASE_URL: &str = "https://api.openweathermap.org/data/2.5/weather";
+const FIND_API_BASE_URL: &str = "https://api.openweathermap.org/data/2.5/find";
+const BOX_CITY_API_BASE_URL: &str = "https://api.openweathermap.org/data/2.5/box/city";
+const FORECAST_API_BASE_URL: &str = "https://api.openweathermap.org/data/2.5/forecast";
+const HOURLY_FORECAST_API_BASE_URL: &str = "https://pro.openweathermap.org/data/2.5/forecast/hourly";
+const DAILY_FORECAST_API_BASE_URL: &str = "https://api.openweathermap.org/data/2.5/forecast/daily";
+// https://api.openweathermap.org/geo/1.0/zip?zip=N7L,CA&appid={api_key}
+const GEOCODING_ZIP_API_BASE_URL: &str = "https://api.openweathermap.org/geo/1.0/zip";
+const GEOCODING_DIRECT_API_BASE_URL: &str = "https://api.openweathermap.org/geo/1.0/direct";
+const GEOCODING_REVERSE_API_BASE_URL: &str = "https://api.openweathermap.org/geo/1.0/reverse";
+const AIR_POLLUTION_API_BASE_URL: &str = "https://api.openweathermap.org/data/2.5/air_pollution";
+const AIR_POLLUTION_FORECAST_API_BASE_URL: &str = "https://api.openweathermap.org/data/2.5/air_pollution/forecast";
+const AIR_POLLUTION_HISTORY_API_BASE_URL: &str = "https://api.openweathermap.org/data/2.5/air_pollution/history";
+
+/// A reusable client for the OpenWeatherMap APIs that takes the place to query per call.
+///
+/// The HTTP connection pool, units and API key are set up once, so many places can be
+/// queried without building a new client each time. Cloning is cheap and clones share
+/// the connection pool. Every other client in this crate is a thin wrapper around it, and
+/// each has a `with_client` constructor to share one `OpenWeatherClient` between them.
+///
+/// # Fields
+/// - `client`: The HTTP client shared by every request.
+/// - `units`: Units of measurement (`standard`, `metric` or `imperial`).
+/// - `api_key`: API key for authenticating requests.
+#[derive(Clone)]
+pub struct OpenWeatherClient {
+    client: reqwest::Client,
+    units: String,
+    api_key: String,
+}
+
+impl OpenWeatherClient {
+    pub fn new(units: String, api_key: String) -> Self {
+        Self::with_client(reqwest::Client::new(), units, api_key)
+    }
+
+    /// Creates a client that uses an existing HTTP connection pool.
+    pub fn with_client(client: reqwest::Client, units: String, api_key: String) -> Self {
+        Self {
+            client,
+            units,
+            api_key,
+        }
+    }
+
+    pub fn set_units(&mut self, units: String) {
+        self.units = units;
+    }
+
+    pub fn get_units(&self) -> String {
+        self.units.clone()
+    }
+
+    /// Unit system of the responses, for use with the derived metrics and wind helpers.
+    pub fn units(&self) -> Units {
+        Units::from_api_name(&self.units)
+    }
+
+    pub fn set_api_key(&mut self, api_key: String) {
+        self.api_key = api_key;
+    }
+
+    /// Fetches the current weather for `query`.
+    pub async fn current(&self, query: &WeatherQuery) -> Result<WeatherResponse, Box<dyn std::error::Error>> {
+        Ok(self.send_current_weather_request(query, None).await?.json().await?)
+    }
+
+    /// Fetches the current weather for `query` using `mode=xml` and parses it into a [`WeatherResponse`].
+    ///
+    /// See [`crate::weather::xml::from_xml_str`] for how XML-only fields are mapped.
+    #[cfg(feature = "xml")]
+    pub async fn current_xml(&self, query: &WeatherQuery) -> Result<WeatherResponse, Box<dyn std::error::Error>> {
+        let body = self.send_current_weather_request(query, Some("xml")).await?.text().await?;
+        crate::weather::xml::from_xml_str(&body)
+    }
+
+    /// Fetches the current weather for `query` using `mode=html` and returns the raw HTML widget markup.
+    pub async fn current_html(&self, query: &WeatherQuery) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(self.send_current_weather_request(query, Some("html")).await?.text().await?)
+    }
+
+    /// Fetches the current weather for up to `count` cities (at most 50) around `coord`.
+    pub async fn cities_in_circle(&self, coord: &Coord, count: u8) -> Result<Vec<CityWeather>, Box<dyn std::error::Error>> {
+        if count == 0 || count > 50 {
+            return Err("City count must be between 1 and 50".into());
+        }
+
+        let mut query = WeatherQuery::Coord(coord.clone()).to_query();
+        query.push(("cnt", count.to_string()));
+        query.push(("units", self.units.clone()));
+
+        Ok(self.send_request(FIND_API_BASE_URL, query).await?.json::<CityWeatherList>().await?.list)
+    }
+
+    /// Fetches the current weather for the cities inside `bbox`, filtered by map `zoom` level.
+    pub async fn cities_in_box(&self, bbox: &BoundingBox, zoom: u8) -> Result<Vec<CityWeather>, Box<dyn std::error::Error>> {
+        let bbox = format!("{},{},{},{},{}", bbox.lon_left, bbox.lat_bottom, bbox.lon_right, bbox.lat_top, zoom);
+
+        let query = vec![
+            ("bbox", bbox),
+            ("units", self.units.clone()),
+        ];
+
+        Ok(self.send_request(BOX_CITY_API_BASE_URL, query).await?.json::<CityWeatherList>().await?.list)
+    }
+
+    /// Fetches the 5 day forecast for `query` in 3-hour steps (40 timestamps, free plan).
+    pub async fn forecast(&self, query: &WeatherQuery) -> Result<Forecast, Box<dyn std::error::Error>> {
+        let query = self.units_query(query);
+        Ok(self.send_request(FORECAST_API_BASE_URL, query).await?.json().await?)
+    }
+
+    /// Fetches the 5 day forecast for `query` using `mode=xml` and parses it into a [`Forecast`].
+    ///
+    /// See [`crate::forecast::xml::from_xml_str`] for how XML-only fields are mapped. The
+    /// forecasts are not offered as HTML.
+    #[cfg(feature = "xml")]
+    pub async fn forecast_xml(&self, query: &WeatherQuery) -> Result<Forecast, Box<dyn std::error::Error>> {
+        let mut query = self.units_query(query);
+        query.push(("mode", "xml".to_string()));
+
+        let body = self.send_request(FORECAST_API_BASE_URL, query).await?.text().await?;
+        crate::forecast::xml::from_xml_str(&body)
+    }
+
+    /// Fetches the hourly forecast for `query`, optionally limited to the first `count` hours (at most 96).
+    pub async fn hourly_forecast(&self, query: &WeatherQuery, count: Option<u8>) -> Result<HourlyForecast, Box<dyn std::error::Error>> {
+        let mut query = self.units_query(query);
+        if let Some(count) = count {
+            check_hourly_count(count)?;
+            query.push(("cnt", count.to_string()));
+        }
+
+        Ok(self.send_request(HOURLY_FORECAST_API_BASE_URL, query).await?.json().await?)
+    }
+
+    /// Fetches the daily forecast for `query` for the next `days` days (between 1 and 16).
+    pub async fn daily_forecast(&self, query: &WeatherQuery, days: u8) -> Result<DailyForecast, Box<dyn std::error::Error>> {
+        check_daily_days(days)?;
+
+        let mut query = self.units_query(query);
+        query.push(("cnt", days.to_string()));
+
+        Ok(self.send_request(DAILY_FORECAST_API_BASE_URL, query).await?.json().await?)
+    }
+
+    /// Resolves a postal code into a [`Location`] with the Geocoding API.
+    pub async fn geocode_zip(&self, postal_code: &PostalCode) -> Result<Location, Box<dyn std::error::Error>> {
+        let query = WeatherQuery::Zip(postal_code.clone()).to_query();
+        Ok(self.send_request(GEOCODING_ZIP_API_BASE_URL, query).await?.json().await?)
+    }
+
+    /// Finds up to `limit` places (between 1 and 5) matching a city name, optionally followed
+    /// by state and country codes (e.g. `London,GB`).
+    pub async fn geocode(&self, name: &str, limit: u8) -> Result<Vec<Place>, Box<dyn std::error::Error>> {
+        check_city_name(name)?;
+        check_geocode_limit(limit)?;
+
+        let query = vec![
+            ("q", name.to_string()),
+            ("limit", limit.to_string()),
+        ];
+
+        Ok(self.send_request(GEOCODING_DIRECT_API_BASE_URL, query).await?.json().await?)
+    }
+
+    /// Finds up to `limit` places (between 1 and 5) near `coord`.
+    pub async fn reverse_geocode(&self, coord: &Coord, limit: u8) -> Result<Vec<Place>, Box<dyn std::error::Error>> {
+        check_geocode_limit(limit)?;
+
+        let mut query = WeatherQuery::Coord(coord.clone()).to_query();
+        query.push(("limit", limit.to_string()));
+
+        Ok(self.send_request(GEOCODING_REVERSE_API_BASE_URL, query).await?.json().await?)
+    }
+
+    /// Fetches the current air pollution at `coord`.
+    pub async fn air_pollution(&self, coord: &Coord) -> Result<AirPollution, Box<dyn std::error::Error>> {
+        let query = WeatherQuery::Coord(coord.clone()).to_query();
+        Ok(self.send_request(AIR_POLLUTION_API_BASE_URL, query).await?.json().await?)
+    }
+
+    /// Fetches the hourly air pollution forecast for the next 4 days at `coord`.
+    pub async fn air_pollution_forecast(&self, coord: &Coord) -> Result<AirPollution, Box<dyn std::error::Error>> {
+        let query = WeatherQuery::Coord(coord.clone()).to_query();
+        Ok(self.send_request(AIR_POLLUTION_FORECAST_API_BASE_URL, query).await?.json().await?)
+    }
+
+    /// Fetches hourly historical air pollution at `coord` between `start` and `end` (unix, UTC).
+    pub async fn air_pollution_history(&self, coord: &Coord, start: i64, end: i64) -> Result<AirPollution, Box<dyn std::error::Error>> {
+        if start > end {
+            return Err("Start time must not be after end time".into());
+        }
+
+        let mut query = WeatherQuery::Coord(coord.clone()).to_query();
+        query.push(("start", start.to_string()));
+        query.push(("end", end.to_string()));
+
+        Ok(self.send_request(AIR_POLLUTION_HISTORY_API_BASE_URL, query).await?.json().await?)
+    }
+
+    async fn send_current_weather_request(&self, query: &WeatherQuery, mode: Option<&str>) -> Result<reqwest::Response, Box<dyn std::error::Error>> {
+        let mut query = self.units_query(query);
+        if let Some(mode) = mode {
+            query.push(("mode", mode.to_string()));
+        }
+
+        self.send_request(WEATHER_API_BASE_URL, query).await
+    }
+
+    fn units_query(&self, query: &WeatherQuery) -> Vec<(&'static str, String)> {
+        let mut query = query.to_query();
+        query.push(("units", self.units.clone()));
+        query
+    }
+
+    /// Sends a GET request with `query` and the API key, failing on non-success statuses.
+    pub(crate) async fn send_request(&self, url: &str, query: Vec<(&str, String)>) -> Result<reqwest::Response, Box<dyn std::error::Error>> {
+        self.send(self.request(reqwest::Method::GET, url).query(&query)).await
+    }
+
+    /// Starts a request on the shared connection pool, to be sent with [`Self::send`].
+    pub(crate) fn request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+        self.client.request(method, url)
+    }
+
+    /// Sends `request` with the API key, failing on non-success statuses.
+    pub(crate) async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response, Box<dyn std::error::Error>> {
+        self.execute(request.query(&[("appid", &self.api_key)])).await
+    }
+
+    /// Sends `request` as is, for URLs that already carry the API key, failing on
+    /// non-success statuses.
+    pub(crate) async fn execute(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response, Box<dyn std::error::Error>> {
+        let request = request.build()?;
+        // Log without the query string, which carries the API key.
+        let url = format!("{}://{}{}", request.url().scheme(), request.url().host_str().unwrap_or_default(), request.url().path());
+        debug_log!("Requesting {}", url);
+
+        let response = self.client.execute(request).await?;
+
+        if !response.status().is_success() {
+            debug_log!("Request to {} failed with status: {}", url, response.status());
+            return Err(ApiError::from_response(response).await.into());
+        }
+
+        Ok(response)
+    }
+}
+
+/// Appends `segment` to `base` as a single percent-encoded path segment, so an ID containing
+/// `/`, `?` or `#` cannot change the request. Empty, `.` and `..` segments, which would
+/// address another resource, are rejected.
+pub(crate) fn url_with_segment(base: &str, segment: &str) -> Result<String, String> {
+    if matches!(segment, "" | "." | "..") {
+        return Err(format!("Invalid ID: {:?}", segment));
+    }
+
+    let mut url = reqwest::Url::parse(base).map_err(|e| e.to_string())?;
+    url.path_segments_mut()
+        .map_err(|_| format!("Cannot append a path segment to {}", base))?
+        .push(segment);
+    Ok(url.into())
+}
+
+/// Rejects an empty city name before a geocoding or weather request is sent.
+pub(crate) fn check_city_name(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("City name must not be empty".to_string());
+    }
+    Ok(())
+}
+
+/// Rejects a geocoding result limit outside 1 to 5.
+pub(crate) fn check_geocode_limit(limit: u8) -> Result<(), String> {
+    if limit == 0 || limit > 5 {
+        return Err("Geocoding limit must be between 1 and 5".to_string());
+    }
+    Ok(())
+}
+
+/// Rejects an hourly forecast length outside 1 to 96 hours.
+pub(crate) fn check_hourly_count(count: u8) -> Result<(), String> {
+    if count == 0 || count > 96 {
+        return Err("Hourly forecast count must be between 1 and 96".to_string());
+    }
+    Ok(())
+}
+
+/// Rejects a daily forecast length outside 1 to 16 days.
+pub(crate) fn check_daily_days(days: u8) -> Result<(), String> {
+    if days == 0 || days > 16 {
+        return Err("Daily forecast day count must be between 1 and 16".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_segments_are_encoded() {
+        let base = "https://api.example.com/v1/items";
+        assert_eq!(url_with_segment(base, "5aaa8052").unwrap(), "https://api.example.com/v1/items/5aaa8052");
+        assert_eq!(url_with_segment(base, "a/b?c#d").unwrap(), "https://api.example.com/v1/items/a%2Fb%3Fc%23d");
+        assert!(url_with_segment(base, "").is_err());
+        assert!(url_with_segment(base, ".").is_err());
+        assert!(url_with_segment(base, "..").is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use std::error::Error;
+use std::fmt;
+
+/// Represents a non-success HTTP response from an OpenWeatherMap API.
+///
+/// Every client returns this error (boxed) when the API answers with an error status, so
+/// callers can recover the status code with `downcast_ref::<ApiError>()`.
+///
+/// Sample JSON (error body returned by the API)
+/// ```json
+/// {
+///     "cod": "404",
+///     "message": "city not found"
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    /// HTTP status code
+    pub status: u16,
+    /// Error message from the response body, if the API provided one
+    pub message: Option<String>,
+}
+
+impl ApiError {
+    /// Builds the error from a non-success response, reading the API's message from the body.
+    pub(crate) async fn from_response(response: reqwest::Response) -> Self {
+        let status = response.status().as_u16();
+        let message = response
+            .json::<serde_json::Value>()
+            .await
+            .ok()
+            .and_then(|body| body.get("message")?.as_str().map(str::to_string))
+            .filter(|message| !message.is_empty());
+
+        ApiError { status, message }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match reqwest::StatusCode::from_u16(self.status) {
+            Ok(status) => write!(f, "API request failed with status: {}", status)?,
+            Err(_) => write!(f, "API request failed with status: {}", self.status)?,
+        }
+        if let Some(message) = &self.message {
+            write!(f, " ({})", message)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ApiError {}
@@ -0,0 +1,49 @@
+use super::types::FireWeatherIndex;
+use crate::client::OpenWeatherClient;
+use crate::query::WeatherQuery;
+use crate::weather::Coord;
+
+const FIRE_WEATHER_API_BASE_URL: &str = "https://api.openweathermap.org/data/2.5/fwi";
+
+/// A client for the Fire Weather Index API.
+///
+/// # Fields
+/// - `client`: The shared client that sends the requests.
+/// - `coord`: Coordinates the index is requested for.
+pub struct FireWeatherClient {
+    client: OpenWeatherClient,
+    coord: Coord,
+}
+
+impl FireWeatherClient {
+    pub fn new(coord: impl Into<Coord>, api_key: String) -> Self {
+        Self::with_client(coord, OpenWeatherClient::new("standard".to_string(), api_key))
+    }
+
+    /// Creates a client that shares the connection pool and API key of `client`.
+    pub fn with_client(coord: impl Into<Coord>, client: OpenWeatherClient) -> Self {
+        Self {
+            client,
+            coord: coord.into(),
+        }
+    }
+
+    pub fn set_coord(&mut self, coord: impl Into<Coord>) {
+        self.coord = coord.into();
+    }
+
+    pub fn get_coord(&self) -> Coord {
+        self.coord.clone()
+    }
+
+    /// Fetches the Fire Weather Index and danger rating, optionally for a past or future
+    /// timestamp `dt` (unix, UTC) instead of the current one.
+    pub async fn get_fire_weather_index(&self, dt: Option<i64>) -> Result<FireWeatherIndex, Box<dyn std::error::Error>> {
+        let mut query = WeatherQuery::Coord(self.coord.clone()).to_query();
+        if let Some(dt) = dt {
+            query.push(("dt", dt.to_string()));
+        }
+
+        Ok(self.client.send_request(FIRE_WEATHER_API_BASE_URL, query).await?.json().await?)
+    }
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::scale::numeric_scale;
+use crate::weather::Coord;
+
+// region: DangerRating
+
+/// Represents the fire danger class derived from the Fire Weather Index, from least to most dangerous.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum DangerRating {
+    Low,
+    Moderate,
+    High,
+    VeryHigh,
+    Extreme,
+    /// Rating not known to this library
+    Unknown(u8),
+}
+
+numeric_scale!(DangerRating {
+    1 => Low,
+    2 => Moderate,
+    3 => High,
+    4 => VeryHigh,
+    5 => Extreme,
+});
+
+impl fmt::Display for DangerRating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            DangerRating::Low => "Low",
+            DangerRating::Moderate => "Moderate",
+            DangerRating::High => "High",
+            DangerRating::VeryHigh => "Very high",
+            DangerRating::Extreme => "Extreme",
+            DangerRating::Unknown(value) => return write!(f, "Unknown ({})", value),
+        };
+        write!(f, "{}", description)
+    }
+}
+
+// endregion
+
+// region: FireWeatherIndex
+
+/// Represents the Fire Weather Index value.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FireWeatherMain {
+    /// Fire Weather Index
+    pub fwi: f64,
+}
+
+/// Represents the danger rating with its description as returned by the API.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FireDanger {
+    /// Danger rating description (e.g., "Moderate")
+    pub description: String,
+    /// Danger rating
+    pub value: DangerRating,
+}
+
+/// Represents the fire weather at one timestamp.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "main": {
+///         "fwi": 20.5
+///     },
+///     "danger_rating": {
+///         "description": "Moderate",
+///         "value": 2
+///     },
+///     "dt": 1752449935
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FireWeatherItem {
+    /// Fire Weather Index value
+    pub main: FireWeatherMain,
+    /// Danger rating
+    pub danger_rating: FireDanger,
+    /// Time of the data, unix, UTC
+    pub dt: i64,
+}
+
+/// Represents the Fire Weather Index API response.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FireWeatherIndex {
+    /// Geographic coordinates of the location
+    pub coord: Coord,
+    /// Fire weather for each timestamp
+    pub list: Vec<FireWeatherItem>,
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratings_order_by_value() {
+        assert!(DangerRating::Low < DangerRating::Extreme);
+        assert!(DangerRating::Unknown(0) < DangerRating::Low);
+        assert!(DangerRating::Unknown(4) < DangerRating::Extreme);
+        let ratings = [DangerRating::Unknown(0), DangerRating::Extreme, DangerRating::High];
+        assert_eq!(ratings.iter().max(), Some(&DangerRating::Extreme));
+    }
+}
@@ -0,0 +1,58 @@
+use super::types::{DailyForecast, Forecast, HourlyForecast};
+use crate::client::OpenWeatherClient;
+use crate::geo::Coord;
+use crate::query::WeatherQuery;
+
+/// A client for retrieving the 5 day (3-hour steps), hourly (4 days) and daily (16 days)
+/// forecasts for a location.
+///
+/// This is a thin wrapper around [`OpenWeatherClient`] bound to one set of coordinates.
+///
+/// # Fields
+/// - `client`: The shared client that sends the requests (the hourly forecast requires a paid plan).
+/// - `coord`: Coordinates the forecast is requested for.
+pub struct ForecastClient {
+    client: OpenWeatherClient,
+    coord: Coord,
+}
+
+impl ForecastClient {
+    pub fn new(coord: impl Into<Coord>, units: String, api_key: String) -> Self {
+        Self::with_client(coord, OpenWeatherClient::new(units, api_key))
+    }
+
+    /// Creates a client that shares the connection pool, units and API key of `client`.
+    pub fn with_client(coord: impl Into<Coord>, client: OpenWeatherClient) -> Self {
+        Self {
+            client,
+            coord: coord.into(),
+        }
+    }
+
+    /// Fetches the 5 day forecast in 3-hour steps (available on the free plan).
+    pub async fn get_forecast(&self) -> Result<Forecast, Box<dyn std::error::Error>> {
+        self.client.forecast(&self.query()).await
+    }
+
+    /// Fetches the 5 day forecast using `mode=xml` and parses it into a [`Forecast`].
+    ///
+    /// See [`super::xml::from_xml_str`] for how XML-only fields are mapped.
+    #[cfg(feature = "xml")]
+    pub async fn get_forecast_xml(&self) -> Result<Forecast, Box<dyn std::error::Error>> {
+        self.client.forecast_xml(&self.query()).await
+    }
+
+    /// Fetches the hourly forecast, optionally limited to the first `count` hours (at most 96).
+    pub async fn get_hourly_forecast(&self, count: Option<u8>) -> Result<HourlyForecast, Box<dyn std::error::Error>> {
+        self.client.hourly_forecast(&self.query(), count).await
+    }
+
+    /// Fetches the daily forecast for the next `days` days (between 1 and 16).
+    pub async fn get_daily_forecast(&self, days: u8) -> Result<DailyForecast, Box<dyn std::error::Error>> {
+        self.client.daily_forecast(&self.query(), days).await
+    }
+
+    fn query(&self) -> WeatherQuery {
+        WeatherQuery::Coord(self.coord.clone())
+    }
+}
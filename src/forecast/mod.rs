@@ -0,0 +1,7 @@
+pub mod types;
+pub mod client;
+#[cfg(feature = "xml")]
+pub mod xml;
+
+pub use types::*;
+pub use client::ForecastClient;
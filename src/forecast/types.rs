@@ -0,0 +1,373 @@
+use serde::{Deserialize, Serialize};
+
+use crate::weather::types::{deserialize_rounded_option, deserialize_timestamp_option};
+use crate::weather::{Clouds, Coord, Main, Precipitation, Weather, Wind};
+
+// region: ForecastCity
+
+/// Represents the city a forecast was calculated for.
+///
+/// Sample JSON
+/// ```json
+/// "city": {
+///     "id": 5920450,
+///     "name": "Chatham-Kent",
+///     "coord": {
+///         "lat": 42.4421,
+///         "lon": -82.1993
+///     },
+///     "country": "CA",
+///     "population": 101647,
+///     "timezone": -14400,
+///     "sunrise": 1752401008,
+///     "sunset": 1752455116
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ForecastCity {
+    /// City ID
+    pub id: i64,
+    /// City name
+    pub name: String,
+    /// Geographic coordinates of the city
+    pub coord: Coord,
+    /// Country code (ISO 3166-1 alpha-2), omitted outside of any country
+    pub country: Option<String>,
+    /// City population (optional)
+    pub population: Option<i64>,
+    /// Timezone shift in seconds from UTC
+    pub timezone: i32,
+    /// Sunrise time, unix, UTC (not returned by the daily forecast, `None` during polar day or night)
+    #[serde(default, deserialize_with = "deserialize_timestamp_option")]
+    pub sunrise: Option<i64>,
+    /// Sunset time, unix, UTC (not returned by the daily forecast, `None` during polar day or night)
+    #[serde(default, deserialize_with = "deserialize_timestamp_option")]
+    pub sunset: Option<i64>,
+}
+
+// endregion
+
+// region: Forecast
+
+/// Represents the part of the day of a forecast step.
+///
+/// Sample JSON
+/// ```json
+/// "sys": {
+///     "pod": "d"
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ForecastSys {
+    /// Part of the day (`d` for day, `n` for night)
+    pub pod: String,
+}
+
+/// Represents one 3-hour step of the 5 day forecast.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "dt": 1752451200,
+///     "main": {
+///         "temp": 27.5,
+///         "feels_like": 29.01,
+///         "temp_min": 26.9,
+///         "temp_max": 27.5,
+///         "pressure": 1014,
+///         "humidity": 63,
+///         "sea_level": 1014,
+///         "grnd_level": 993
+///     },
+///     "weather": [
+///         {
+///             "id": 500,
+///             "main": "Rain",
+///             "description": "light rain",
+///             "icon": "10n"
+///         }
+///     ],
+///     "clouds": {
+///         "all": 40
+///     },
+///     "wind": {
+///         "speed": 3.4,
+///         "deg": 221,
+///         "gust": 5.1
+///     },
+///     "visibility": 10000,
+///     "pop": 0.32,
+///     "rain": {
+///         "3h": 0.41
+///     },
+///     "sys": {
+///         "pod": "n"
+///     },
+///     "dt_txt": "2025-07-14 00:00:00"
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ForecastItem {
+    /// Time of the forecasted data, unix, UTC
+    pub dt: i64,
+    /// Main weather measurements (temperature, pressure, humidity, etc.)
+    pub main: Main,
+    /// Weather condition information (can be multiple conditions)
+    pub weather: Vec<Weather>,
+    /// Cloud coverage information
+    pub clouds: Clouds,
+    /// Wind information
+    pub wind: Wind,
+    /// Visibility in meters (optional)
+    pub visibility: Option<i64>,
+    /// Probability of precipitation, from 0 to 1
+    pub pop: Option<f64>,
+    /// Rain volume for the last 3 hours (optional)
+    pub rain: Option<Precipitation>,
+    /// Snow volume for the last 3 hours (optional)
+    pub snow: Option<Precipitation>,
+    /// Part of the day (optional)
+    pub sys: Option<ForecastSys>,
+    /// Time of the forecasted data, ISO format, UTC
+    pub dt_txt: Option<String>,
+}
+
+/// Represents the 5 day forecast in 3-hour steps (up to 40 timestamps).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Forecast {
+    /// Number of timestamps returned
+    pub cnt: i32,
+    /// Forecast for each 3-hour step
+    pub list: Vec<ForecastItem>,
+    /// City the forecast was calculated for
+    pub city: ForecastCity,
+}
+
+// endregion
+
+// region: HourlyForecast
+
+/// Represents one hour of the hourly forecast.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "dt": 1752451200,
+///     "main": {
+///         "temp": 27.5,
+///         "feels_like": 29.01,
+///         "temp_min": 27.5,
+///         "temp_max": 27.5,
+///         "pressure": 1014,
+///         "humidity": 63,
+///         "sea_level": 1014,
+///         "grnd_level": 993
+///     },
+///     "weather": [
+///         {
+///             "id": 801,
+///             "main": "Clouds",
+///             "description": "few clouds",
+///             "icon": "02d"
+///         }
+///     ],
+///     "clouds": {
+///         "all": 20
+///     },
+///     "wind": {
+///         "speed": 3.4,
+///         "deg": 221,
+///         "gust": 5.1
+///     },
+///     "visibility": 10000,
+///     "pop": 0.12,
+///     "rain": {
+///         "1h": 0.21
+///     },
+///     "dt_txt": "2025-07-14 00:00:00"
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HourlyForecastItem {
+    /// Time of the forecasted data, unix, UTC
+    pub dt: i64,
+    /// Main weather measurements (temperature, pressure, humidity, etc.)
+    pub main: Main,
+    /// Weather condition information (can be multiple conditions)
+    pub weather: Vec<Weather>,
+    /// Cloud coverage information
+    pub clouds: Clouds,
+    /// Wind information
+    pub wind: Wind,
+    /// Visibility in meters (optional)
+    pub visibility: Option<i64>,
+    /// Probability of precipitation, from 0 to 1
+    pub pop: Option<f64>,
+    /// Rain volume (optional)
+    pub rain: Option<Precipitation>,
+    /// Snow volume (optional)
+    pub snow: Option<Precipitation>,
+    /// Time of the forecasted data, ISO format, UTC
+    pub dt_txt: Option<String>,
+}
+
+/// Represents the hourly forecast (up to 4 days, 96 timestamps).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HourlyForecast {
+    /// Number of timestamps returned
+    pub cnt: i32,
+    /// Forecast for each hour
+    pub list: Vec<HourlyForecastItem>,
+    /// City the forecast was calculated for
+    pub city: ForecastCity,
+}
+
+// endregion
+
+// region: DailyForecast
+
+/// Represents the temperatures of one day at different times of day.
+///
+/// Sample JSON
+/// ```json
+/// "temp": {
+///     "day": 28.1,
+///     "min": 18.44,
+///     "max": 29.9,
+///     "night": 19.52,
+///     "eve": 26.7,
+///     "morn": 18.9
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DailyTemperature {
+    /// Day temperature
+    pub day: f64,
+    /// Minimum daily temperature
+    pub min: f64,
+    /// Maximum daily temperature
+    pub max: f64,
+    /// Night temperature
+    pub night: f64,
+    /// Evening temperature
+    pub eve: f64,
+    /// Morning temperature
+    pub morn: f64,
+}
+
+/// Represents the human-perceived temperatures of one day at different times of day.
+///
+/// Sample JSON
+/// ```json
+/// "feels_like": {
+///     "day": 29.4,
+///     "night": 19.8,
+///     "eve": 27.6,
+///     "morn": 19.1
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DailyFeelsLike {
+    /// Day temperature perception
+    pub day: f64,
+    /// Night temperature perception
+    pub night: f64,
+    /// Evening temperature perception
+    pub eve: f64,
+    /// Morning temperature perception
+    pub morn: f64,
+}
+
+/// Represents one day of the daily forecast.
+///
+/// Unlike the hourly forecast, wind, clouds and precipitation are flat fields.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "dt": 1752426000,
+///     "sunrise": 1752401008,
+///     "sunset": 1752455116,
+///     "temp": {
+///         "day": 28.1,
+///         "min": 18.44,
+///         "max": 29.9,
+///         "night": 19.52,
+///         "eve": 26.7,
+///         "morn": 18.9
+///     },
+///     "feels_like": {
+///         "day": 29.4,
+///         "night": 19.8,
+///         "eve": 27.6,
+///         "morn": 19.1
+///     },
+///     "pressure": 1014,
+///     "humidity": 58,
+///     "weather": [
+///         {
+///             "id": 500,
+///             "main": "Rain",
+///             "description": "light rain",
+///             "icon": "10d"
+///         }
+///     ],
+///     "speed": 3.9,
+///     "deg": 214,
+///     "gust": 7.2,
+///     "clouds": 35,
+///     "pop": 0.4,
+///     "rain": 1.2
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DailyForecastItem {
+    /// Time of the forecasted data, unix, UTC
+    pub dt: i64,
+    /// Sunrise time, unix, UTC (`None` when the sun does not rise or set that day)
+    #[serde(default, deserialize_with = "deserialize_timestamp_option")]
+    pub sunrise: Option<i64>,
+    /// Sunset time, unix, UTC (`None` when the sun does not rise or set that day)
+    #[serde(default, deserialize_with = "deserialize_timestamp_option")]
+    pub sunset: Option<i64>,
+    /// Temperatures at different times of day
+    pub temp: DailyTemperature,
+    /// Human-perceived temperatures at different times of day
+    pub feels_like: DailyFeelsLike,
+    /// Atmospheric pressure on the sea level in hPa (optional)
+    #[serde(default, deserialize_with = "deserialize_rounded_option")]
+    pub pressure: Option<i32>,
+    /// Humidity percentage (optional)
+    #[serde(default, deserialize_with = "deserialize_rounded_option")]
+    pub humidity: Option<i32>,
+    /// Weather condition information (can be multiple conditions)
+    pub weather: Vec<Weather>,
+    /// Wind speed (units vary by API request: m/s for metric, mph for imperial)
+    pub speed: f64,
+    /// Wind direction in degrees (meteorological), if reported
+    #[serde(default, deserialize_with = "deserialize_rounded_option")]
+    pub deg: Option<i32>,
+    /// Wind gust speed (optional, same units as speed)
+    pub gust: Option<f64>,
+    /// Cloudiness, %
+    pub clouds: i32,
+    /// Probability of precipitation, from 0 to 1
+    pub pop: Option<f64>,
+    /// Rain volume for the day, mm (optional)
+    pub rain: Option<f64>,
+    /// Snow volume for the day, mm (optional)
+    pub snow: Option<f64>,
+}
+
+/// Represents the daily forecast (up to 16 days).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DailyForecast {
+    /// Number of days returned
+    pub cnt: i32,
+    /// Forecast for each day
+    pub list: Vec<DailyForecastItem>,
+    /// City the forecast was calculated for
+    pub city: ForecastCity,
+}
+
+// endregion
@@ -0,0 +1,236 @@
+//! Deserialization of the `mode=xml` 5 day / 3-hour forecast payload.
+//!
+//! Like [`crate::weather::xml`], the document is parsed into private mirror structs and
+//! converted into the regular [`Forecast`]. The hourly and daily forecasts are only
+//! offered as JSON, and no forecast is offered as HTML.
+//!
+//! Sample XML
+//! ```xml
+//! <weatherdata>
+//!     <location>
+//!         <name>Chatham-Kent</name>
+//!         <type></type>
+//!         <country>CA</country>
+//!         <timezone>-14400</timezone>
+//!         <location altitude="0" latitude="42.4421" longitude="-82.1993" geobase="geonames" geobaseid="5920450"/>
+//!     </location>
+//!     <sun rise="2025-07-13T10:03:28" set="2025-07-14T01:05:16"/>
+//!     <forecast>
+//!         <time from="2025-07-14T00:00:00" to="2025-07-14T03:00:00">
+//!             <symbol number="500" name="light rain" var="10n"/>
+//!             <precipitation probability="0.32" unit="3h" value="0.41" type="rain"/>
+//!             <windDirection deg="221" code="SW" name="Southwest"/>
+//!             <windSpeed mps="3.4" unit="m/s" name="Gentle Breeze"/>
+//!             <windGust gust="5.1" unit="m/s"/>
+//!             <temperature unit="celsius" value="27.5" min="26.9" max="27.5"/>
+//!             <feels_like value="29.01" unit="celsius"/>
+//!             <pressure unit="hPa" value="1014"/>
+//!             <humidity value="63" unit="%"/>
+//!             <clouds value="scattered clouds" all="40" unit="%"/>
+//!             <visibility value="10000"/>
+//!         </time>
+//!     </forecast>
+//! </weatherdata>
+//! ```
+
+use quick_xml::DeError;
+use serde::de::Error as _;
+use serde::Deserialize;
+
+use super::types::{Forecast, ForecastCity, ForecastItem, ForecastSys};
+use crate::weather::xml::{parse_sun_timestamp, parse_utc_timestamp, weather_group};
+use crate::weather::{Clouds, Coord, Main, Precipitation, Weather, Wind};
+
+#[derive(Debug, Deserialize)]
+struct XmlWeatherData {
+    location: XmlLocation,
+    sun: Option<XmlSun>,
+    forecast: XmlForecast,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlLocation {
+    name: String,
+    country: Option<String>,
+    timezone: i32,
+    location: XmlGeo,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlGeo {
+    #[serde(rename = "@latitude")]
+    latitude: f64,
+    #[serde(rename = "@longitude")]
+    longitude: f64,
+    #[serde(rename = "@geobaseid")]
+    geobaseid: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlSun {
+    #[serde(rename = "@rise")]
+    rise: Option<String>,
+    #[serde(rename = "@set")]
+    set: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlForecast {
+    #[serde(default)]
+    time: Vec<XmlTime>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct XmlTime {
+    #[serde(rename = "@from")]
+    from: String,
+    symbol: XmlSymbol,
+    precipitation: Option<XmlPrecipitation>,
+    wind_direction: Option<XmlWindDirection>,
+    wind_speed: XmlWindSpeed,
+    wind_gust: Option<XmlWindGust>,
+    temperature: XmlTemperature,
+    #[serde(rename = "feels_like")]
+    feels_like: Option<XmlValue<f64>>,
+    pressure: Option<XmlValue<i32>>,
+    humidity: Option<XmlValue<i32>>,
+    clouds: XmlClouds,
+    visibility: Option<XmlValue<i64>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlSymbol {
+    #[serde(rename = "@number")]
+    number: i32,
+    #[serde(rename = "@name")]
+    name: String,
+    #[serde(rename = "@var")]
+    var: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlPrecipitation {
+    #[serde(rename = "@probability")]
+    probability: Option<f64>,
+    #[serde(rename = "@value")]
+    value: Option<f64>,
+    #[serde(rename = "@type")]
+    kind: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlWindDirection {
+    #[serde(rename = "@deg")]
+    deg: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlWindSpeed {
+    #[serde(rename = "@mps")]
+    mps: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlWindGust {
+    #[serde(rename = "@gust")]
+    gust: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlTemperature {
+    #[serde(rename = "@value")]
+    value: Option<f64>,
+    #[serde(rename = "@min")]
+    min: Option<f64>,
+    #[serde(rename = "@max")]
+    max: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlValue<T> {
+    #[serde(rename = "@value")]
+    value: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlClouds {
+    #[serde(rename = "@all")]
+    all: i32,
+}
+
+/// Parses a `mode=xml` 5 day forecast document into a [`Forecast`].
+///
+/// Fields that only exist in the JSON payload are left empty: the city population and the
+/// sea/ground level pressures are `None`. `cnt` is the number of steps in the document, the
+/// part of the day comes from the icon suffix (`d`/`n`), and `dt_txt` is rebuilt from the
+/// step start time.
+///
+/// Malformed documents and values the response types reject are both reported as
+/// [`DeError`].
+pub fn from_xml_str(xml: &str) -> Result<Forecast, Box<dyn std::error::Error>> {
+    let data: XmlWeatherData = quick_xml::de::from_str(xml)?;
+    Ok(forecast(data).map_err(DeError::custom)?)
+}
+
+fn forecast(data: XmlWeatherData) -> Result<Forecast, String> {
+    let list = data.forecast.time.into_iter().map(forecast_item).collect::<Result<Vec<_>, _>>()?;
+    let location = data.location;
+
+    Ok(Forecast {
+        cnt: list.len() as i32,
+        list,
+        city: ForecastCity {
+            id: location.location.geobaseid,
+            name: location.name,
+            coord: Coord::new(location.location.longitude, location.location.latitude)?,
+            country: location.country.filter(|country| !country.is_empty()),
+            population: None,
+            timezone: location.timezone,
+            sunrise: parse_sun_timestamp(data.sun.as_ref().and_then(|sun| sun.rise.as_deref()))?,
+            sunset: parse_sun_timestamp(data.sun.as_ref().and_then(|sun| sun.set.as_deref()))?,
+        },
+    })
+}
+
+fn forecast_item(time: XmlTime) -> Result<ForecastItem, String> {
+    let precipitation = time.precipitation;
+    let volume = |kind: &str| {
+        precipitation.as_ref()
+            .filter(|p| p.kind.as_deref() == Some(kind))
+            .and_then(|p| p.value)
+            .map(|value| Precipitation { one_hour: None, three_hours: Some(value) })
+    };
+
+    Ok(ForecastItem {
+        dt: parse_utc_timestamp(&time.from)?,
+        main: Main::new(
+            time.temperature.value,
+            time.feels_like.map(|v| v.value),
+            time.temperature.min,
+            time.temperature.max,
+            time.pressure.map(|v| v.value),
+            time.humidity.map(|v| v.value),
+            None,
+            None,
+        )?,
+        weather: vec![Weather {
+            id: time.symbol.number,
+            main: weather_group(time.symbol.number).to_string(),
+            description: time.symbol.name,
+            icon: time.symbol.var.clone(),
+        }],
+        clouds: Clouds::new(time.clouds.all)?,
+        wind: Wind::new(
+            time.wind_speed.mps,
+            time.wind_direction.and_then(|v| v.deg).map(|deg| deg.round() as i32),
+            time.wind_gust.and_then(|g| g.gust),
+        )?,
+        visibility: time.visibility.map(|v| v.value),
+        pop: precipitation.as_ref().and_then(|p| p.probability),
+        rain: volume("rain"),
+        snow: volume("snow"),
+        sys: time.symbol.var.chars().last().map(|pod| ForecastSys { pod: pod.to_string() }),
+        dt_txt: Some(time.from.replace('T', " ")),
+    })
+}
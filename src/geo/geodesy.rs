@@ -0,0 +1,324 @@
+//! Geodesic calculations on [`Coord`]: distances, bearings, destination points and
+//! coordinate rounding.
+//!
+//! Distances are in meters and angles in decimal degrees. The haversine functions use a
+//! spherical Earth (error up to about 0.5%); Vincenty's formulae use the WGS-84 ellipsoid
+//! and are accurate to well under a millimeter.
+
+use super::types::{BoundingBox, Coord};
+
+/// Mean Earth radius (IUGG), meters.
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// WGS-84 semi-major axis, meters.
+const WGS84_A: f64 = 6_378_137.0;
+
+/// WGS-84 flattening.
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+/// Maximum number of iterations of Vincenty's inverse formula.
+const VINCENTY_ITERATIONS: usize = 200;
+
+/// Largest number of decimals [`Coord::rounded`] rounds to.
+pub const MAX_ROUNDING_DECIMALS: u32 = 12;
+
+impl Coord {
+    /// Great-circle distance to `other` in meters, on a spherical Earth.
+    pub fn haversine_distance(&self, other: &Coord) -> f64 {
+        let lat1 = self.lat.to_radians();
+        let lat2 = other.lat.to_radians();
+        let delta_lat = lat2 - lat1;
+        let delta_lon = (other.lon - self.lon).to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS * a.sqrt().min(1.0).asin()
+    }
+
+    /// Geodesic distance to `other` in meters on the WGS-84 ellipsoid (Vincenty's inverse formula).
+    ///
+    /// Returns `None` if the formula does not converge, which happens for nearly antipodal points.
+    pub fn vincenty_distance(&self, other: &Coord) -> Option<f64> {
+        let b = WGS84_A * (1.0 - WGS84_F);
+        let l = (other.lon - self.lon).to_radians();
+        let u1 = ((1.0 - WGS84_F) * self.lat.to_radians().tan()).atan();
+        let u2 = ((1.0 - WGS84_F) * other.lat.to_radians().tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+        for _ in 0..VINCENTY_ITERATIONS {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+                .sqrt();
+            if sin_sigma == 0.0 {
+                return Some(0.0);
+            }
+
+            let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            let sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+            // Both points on the equator: cos²α is zero and the term vanishes.
+            let cos_2sigma_m = if cos_sq_alpha == 0.0 { 0.0 } else { cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha };
+
+            let c = WGS84_F / 16.0 * cos_sq_alpha * (4.0 + WGS84_F * (4.0 - 3.0 * cos_sq_alpha));
+            let previous = lambda;
+            lambda = l + (1.0 - c) * WGS84_F * sin_alpha
+                * (sigma + c * sin_sigma * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+
+            if (lambda - previous).abs() < 1e-12 {
+                let u_sq = cos_sq_alpha * (WGS84_A * WGS84_A - b * b) / (b * b);
+                let big_a = 1.0 + u_sq / 16_384.0 * (4_096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+                let big_b = u_sq / 1_024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+                let delta_sigma = big_b * sin_sigma
+                    * (cos_2sigma_m + big_b / 4.0
+                        * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                            - big_b / 6.0 * cos_2sigma_m * (-3.0 + 4.0 * sin_sigma * sin_sigma) * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+
+                return Some(b * big_a * (sigma - delta_sigma));
+            }
+        }
+
+        None
+    }
+
+    /// Initial bearing (forward azimuth) towards `other`, in degrees clockwise from north (0 to 360).
+    pub fn initial_bearing(&self, other: &Coord) -> f64 {
+        let lat1 = self.lat.to_radians();
+        let lat2 = other.lat.to_radians();
+        let delta_lon = (other.lon - self.lon).to_radians();
+
+        let y = delta_lon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+        y.atan2(x).to_degrees().rem_euclid(360.0)
+    }
+
+    /// Point reached by travelling `distance` meters from here along a great circle with
+    /// initial `bearing` (degrees clockwise from north), on a spherical Earth.
+    ///
+    /// Returns an error unless `bearing` and `distance` are finite.
+    pub fn destination(&self, bearing: f64, distance: f64) -> Result<Coord, String> {
+        if !bearing.is_finite() || !distance.is_finite() {
+            return Err("Bearing and distance must be finite numbers".to_string());
+        }
+
+        let lat1 = self.lat.to_radians();
+        let lon1 = self.lon.to_radians();
+        let bearing = bearing.to_radians();
+        let angular = distance / EARTH_RADIUS;
+
+        let lat2 = (lat1.sin() * angular.cos() + lat1.cos() * angular.sin() * bearing.cos()).asin();
+        let lon2 = lon1 + (bearing.sin() * angular.sin() * lat1.cos()).atan2(angular.cos() - lat1.sin() * lat2.sin());
+
+        Ok(Coord {
+            lon: normalize_longitude(lon2.to_degrees()),
+            lat: lat2.to_degrees().clamp(-90.0, 90.0),
+        })
+    }
+
+    /// Smallest bounding box containing every point within `radius` meters.
+    ///
+    /// When the circle reaches a pole or crosses the antimeridian the box spans all
+    /// longitudes, since [`BoundingBox`] cannot wrap around. Returns an error unless `radius`
+    /// is finite and not negative.
+    pub fn bounding_box(&self, radius: f64) -> Result<BoundingBox, String> {
+        if !radius.is_finite() || radius < 0.0 {
+            return Err("Radius must be a non-negative number of meters".to_string());
+        }
+
+        let angular = (radius / EARTH_RADIUS).to_degrees();
+        let lat_bottom = self.lat - angular;
+        let lat_top = self.lat + angular;
+
+        let full_width = || BoundingBox::new(-180.0, lat_bottom.max(-90.0), 180.0, lat_top.min(90.0));
+        if lat_bottom <= -90.0 || lat_top >= 90.0 {
+            return full_width();
+        }
+
+        // Longitude span at the latitude where the circle is tangent to a meridian.
+        let lat = self.lat.to_radians();
+        let delta_lon = ((radius / EARTH_RADIUS).sin() / lat.cos()).min(1.0).asin().to_degrees();
+        let lon_left = self.lon - delta_lon;
+        let lon_right = self.lon + delta_lon;
+        if lon_left < -180.0 || lon_right > 180.0 {
+            return full_width();
+        }
+
+        BoundingBox::new(lon_left, lat_bottom, lon_right, lat_top)
+    }
+
+    /// Coordinates rounded to `decimals` decimal places (2 decimals is roughly 1 km).
+    ///
+    /// More than [`MAX_ROUNDING_DECIMALS`] decimals is treated as that many, which is already
+    /// finer than an `f64` can resolve for every longitude.
+    pub fn rounded(&self, decimals: u32) -> Coord {
+        let scale = 10f64.powi(decimals.min(MAX_ROUNDING_DECIMALS) as i32);
+        Coord {
+            lon: (self.lon * scale).round() / scale,
+            lat: (self.lat * scale).round() / scale,
+        }
+    }
+
+    /// Coordinates snapped to the nearest node of a grid with `step` degrees spacing.
+    ///
+    /// Returns an error unless `step` is positive and finite.
+    pub fn snapped(&self, step: f64) -> Result<Coord, String> {
+        if !step.is_finite() || step <= 0.0 {
+            return Err("Grid step must be a positive number of degrees".to_string());
+        }
+
+        Ok(Coord {
+            lon: normalize_longitude((self.lon / step).round() * step),
+            lat: ((self.lat / step).round() * step).clamp(-90.0, 90.0),
+        })
+    }
+}
+
+/// Wraps a longitude into the range -180 to 180 degrees.
+fn normalize_longitude(lon: f64) -> f64 {
+    let wrapped = (lon + 180.0).rem_euclid(360.0) - 180.0;
+    if wrapped == -180.0 && lon > 0.0 { 180.0 } else { wrapped }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coord(lon: f64, lat: f64) -> Coord {
+        Coord::new(lon, lat).unwrap()
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!((actual - expected).abs() <= tolerance, "expected {} ± {}, got {}", expected, tolerance, actual);
+    }
+
+    #[test]
+    fn haversine_known_distances() {
+        // One degree of longitude on the equator is R·π/180.
+        assert_close(coord(0.0, 0.0).haversine_distance(&coord(1.0, 0.0)), 111_195.08, 0.01);
+        // London to Paris is about 343.5 km on a sphere.
+        let london = coord(-0.1278, 51.5074);
+        let paris = coord(2.3522, 48.8566);
+        assert_close(london.haversine_distance(&paris), 343_556.5, 1.0);
+        assert_eq!(paris.haversine_distance(&london), london.haversine_distance(&paris));
+        assert_eq!(london.haversine_distance(&london), 0.0);
+    }
+
+    #[test]
+    fn vincenty_matches_geoscience_australia_example() {
+        // Flinders Peak to Buninyong, the worked example for Vincenty's inverse formula.
+        let flinders_peak = coord(144.424_867_89, -37.951_033_42);
+        let buninyong = coord(143.926_495_54, -37.652_821_14);
+        assert_close(flinders_peak.vincenty_distance(&buninyong).unwrap(), 54_972.271, 0.001);
+        assert_eq!(flinders_peak.vincenty_distance(&flinders_peak), Some(0.0));
+    }
+
+    #[test]
+    fn vincenty_does_not_converge_for_nearly_antipodal_points() {
+        assert_eq!(coord(0.0, 0.0).vincenty_distance(&coord(179.7, 0.5)), None);
+    }
+
+    #[test]
+    fn initial_bearing_cardinal_directions() {
+        let origin = coord(0.0, 0.0);
+        assert_close(origin.initial_bearing(&coord(0.0, 10.0)), 0.0, 1e-9);
+        assert_close(origin.initial_bearing(&coord(10.0, 0.0)), 90.0, 1e-9);
+        assert_close(origin.initial_bearing(&coord(0.0, -10.0)), 180.0, 1e-9);
+        assert_close(origin.initial_bearing(&coord(-10.0, 0.0)), 270.0, 1e-9);
+    }
+
+    #[test]
+    fn destination_round_trips_with_distance_and_bearing() {
+        let start = coord(-0.1278, 51.5074);
+        let end = start.destination(123.0, 250_000.0).unwrap();
+        assert_close(start.haversine_distance(&end), 250_000.0, 1e-6);
+        assert_close(start.initial_bearing(&end), 123.0, 1e-9);
+
+        let east = coord(0.0, 0.0).destination(90.0, 111_195.08).unwrap();
+        assert_close(east.lon, 1.0, 1e-6);
+        assert_close(east.lat, 0.0, 1e-9);
+    }
+
+    #[test]
+    fn destination_wraps_across_the_antimeridian() {
+        let end = coord(179.5, 0.0).destination(90.0, 111_195.08).unwrap();
+        assert_close(end.lon, -179.5, 1e-6);
+    }
+
+    #[test]
+    fn bounding_box_contains_the_circle() {
+        let center = coord(-0.1278, 51.5074);
+        let bbox = center.bounding_box(10_000.0).unwrap();
+        for bearing in [0.0, 90.0, 180.0, 270.0, 45.0, 225.0] {
+            let edge = center.destination(bearing, 10_000.0).unwrap();
+            assert!(edge.lon >= bbox.lon_left - 1e-9 && edge.lon <= bbox.lon_right + 1e-9);
+            assert!(edge.lat >= bbox.lat_bottom - 1e-9 && edge.lat <= bbox.lat_top + 1e-9);
+        }
+        assert_close(bbox.lat_top - center.lat, 10_000.0 / 111_195.08, 1e-6);
+    }
+
+    #[test]
+    fn bounding_box_spans_all_longitudes_at_poles_and_antimeridian() {
+        let polar = coord(10.0, 89.95).bounding_box(10_000.0).unwrap();
+        assert_eq!((polar.lon_left, polar.lon_right, polar.lat_top), (-180.0, 180.0, 90.0));
+
+        let date_line = coord(179.99, 0.0).bounding_box(10_000.0).unwrap();
+        assert_eq!((date_line.lon_left, date_line.lon_right), (-180.0, 180.0));
+        assert!(date_line.lat_bottom < 0.0 && date_line.lat_top > 0.0);
+    }
+
+    #[test]
+    fn destination_rejects_non_finite_input() {
+        let origin = coord(0.0, 0.0);
+        assert!(origin.destination(90.0, f64::NAN).is_err());
+        assert!(origin.destination(90.0, f64::INFINITY).is_err());
+        assert!(origin.destination(f64::NAN, 1_000.0).is_err());
+
+        let west = origin.destination(90.0, -111_195.08).unwrap();
+        assert_close(west.lon, -1.0, 1e-6);
+    }
+
+    #[test]
+    fn bounding_box_rejects_invalid_radii() {
+        let center = coord(-0.1278, 51.5074);
+        assert!(center.bounding_box(-1.0).is_err());
+        assert!(center.bounding_box(f64::NAN).is_err());
+        assert!(center.bounding_box(f64::INFINITY).is_err());
+
+        let point = center.bounding_box(0.0).unwrap();
+        assert_eq!((point.lon_left, point.lat_bottom), (center.lon, center.lat));
+        assert_eq!((point.lon_right, point.lat_top), (center.lon, center.lat));
+
+        let world = center.bounding_box(30_000_000.0).unwrap();
+        assert_eq!((world.lon_left, world.lat_bottom, world.lon_right, world.lat_top), (-180.0, -90.0, 180.0, 90.0));
+    }
+
+    #[test]
+    fn rounded_clamps_the_number_of_decimals() {
+        let point = coord(-179.123_456_789_012_3, 89.987_654_321_098_7);
+        for decimals in [MAX_ROUNDING_DECIMALS, 20, 400, u32::MAX] {
+            let rounded = point.rounded(decimals);
+            assert!(rounded.lon.is_finite() && rounded.lat.is_finite(), "{} decimals", decimals);
+            assert_close(rounded.lon, point.lon, 1e-12);
+            assert_close(rounded.lat, point.lat, 1e-12);
+        }
+        assert_eq!(point.rounded(0).lon, -179.0);
+    }
+
+    #[test]
+    fn rounded_and_snapped() {
+        let point = coord(1.234_56, -2.345_67);
+        let rounded = point.rounded(2);
+        assert_eq!((rounded.lon, rounded.lat), (1.23, -2.35));
+
+        let snapped = point.snapped(0.25).unwrap();
+        assert_eq!((snapped.lon, snapped.lat), (1.25, -2.25));
+
+        let edge = coord(179.9, 89.9).snapped(0.5).unwrap();
+        assert_eq!((edge.lon, edge.lat), (180.0, 90.0));
+
+        assert!(point.snapped(0.0).is_err());
+        assert!(point.snapped(f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+//! Geographic primitives shared by every client: coordinates, bounding boxes and
+//! geodesic calculations.
+
+pub mod types;
+pub mod geodesy;
+
+pub use types::*;
@@ -0,0 +1,210 @@
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+// region: Coord
+
+/// Represents geographic coordinates with longitude and latitude in decimal degrees.
+///
+/// Provides validation to ensure longitude is between -180 and 180 degrees,
+/// and latitude is between -90 and 90 degrees.
+///
+/// This is the coordinate type shared by every client. It deserializes from an object
+/// (`lon`/`lat`, lowercase, capitalized or uppercase) or from a GeoJSON-style
+/// `[lon, lat]` array, and always serializes as an object.
+///
+/// Sample JSON
+/// ```json
+/// "coord": {
+///     "lon": -82.1993,
+///     "lat": 42.4421
+/// }
+/// ```
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct Coord {
+    /// Longitude in decimal degrees
+    pub lon: f64,
+    /// Latitude in decimal degrees
+    pub lat: f64,
+}
+
+impl Coord {
+    pub fn new(lon: f64, lat: f64) -> Result<Self, String> {
+        if !(-180.0..=180.0).contains(&lon) {
+            return Err("Longitude must be between -180 and 180 degrees".to_string());
+        }
+        if !(-90.0..=90.0).contains(&lat) {
+            return Err("Latitude must be between -90 and 90 degrees".to_string());
+        }
+        Ok(Coord { lon, lat })
+    }
+}
+
+impl<'de> Deserialize<'de> for Coord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum CoordShape {
+            Object {
+                #[serde(alias = "Lon", alias = "LON")]
+                lon: f64,
+                #[serde(alias = "Lat", alias = "LAT")]
+                lat: f64,
+            },
+            Array([f64; 2]),
+        }
+
+        let (lon, lat) = match CoordShape::deserialize(deserializer)? {
+            CoordShape::Object { lon, lat } => (lon, lat),
+            CoordShape::Array([lon, lat]) => (lon, lat),
+        };
+        Coord::new(lon, lat).map_err(de::Error::custom)
+    }
+}
+
+/// Serde helpers for APIs that spell the fields out as `latitude`/`longitude`, such as the
+/// Weather Stations API. Use with `#[serde(flatten, with = "crate::geo::types::long_names")]`.
+pub(crate) mod long_names {
+    use super::Coord;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct LongNames {
+        latitude: f64,
+        longitude: f64,
+    }
+
+    pub fn serialize<S: Serializer>(coord: &Coord, serializer: S) -> Result<S::Ok, S::Error> {
+        LongNames { latitude: coord.lat, longitude: coord.lon }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Coord, D::Error> {
+        let LongNames { latitude, longitude } = LongNames::deserialize(deserializer)?;
+        Coord::new(longitude, latitude).map_err(de::Error::custom)
+    }
+}
+
+/// Serde helpers for GeoJSON-style `[lon, lat]` positions, which [`Coord`] reads but does not
+/// write. Use with `#[serde(with = "crate::geo::types::lon_lat_array")]`.
+pub(crate) mod lon_lat_array {
+    use super::Coord;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(coord: &Coord, serializer: S) -> Result<S::Ok, S::Error> {
+        [coord.lon, coord.lat].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Coord, D::Error> {
+        Coord::deserialize(deserializer)
+    }
+}
+
+/// Serde helpers for `[lat, lon]` positions, such as the route points of the Road Risk API.
+/// Use with `#[serde(with = "crate::geo::types::lat_lon_array")]`.
+pub(crate) mod lat_lon_array {
+    use super::Coord;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(coord: &Coord, serializer: S) -> Result<S::Ok, S::Error> {
+        [coord.lat, coord.lon].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Coord, D::Error> {
+        let [lat, lon] = <[f64; 2]>::deserialize(deserializer)?;
+        Coord::new(lon, lat).map_err(de::Error::custom)
+    }
+}
+
+// endregion
+
+// region: BoundingBox
+
+/// Represents a rectangular geographic area in decimal degrees.
+///
+/// Provides validation to ensure all edges are within coordinate ranges and that
+/// the left/bottom edges do not exceed the right/top edges.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BoundingBox {
+    /// Western edge longitude in decimal degrees
+    pub lon_left: f64,
+    /// Southern edge latitude in decimal degrees
+    pub lat_bottom: f64,
+    /// Eastern edge longitude in decimal degrees
+    pub lon_right: f64,
+    /// Northern edge latitude in decimal degrees
+    pub lat_top: f64,
+}
+
+impl BoundingBox {
+    pub fn new(lon_left: f64, lat_bottom: f64, lon_right: f64, lat_top: f64) -> Result<Self, String> {
+        Coord::new(lon_left, lat_bottom)?;
+        Coord::new(lon_right, lat_top)?;
+        if lon_left > lon_right {
+            return Err("Left longitude must not be greater than right longitude".to_string());
+        }
+        if lat_bottom > lat_top {
+            return Err("Bottom latitude must not be greater than top latitude".to_string());
+        }
+        Ok(BoundingBox { lon_left, lat_bottom, lon_right, lat_top })
+    }
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coord_deserializes_from_object_and_array() {
+        let object: Coord = serde_json::from_str(r#"{"lat": 42.4421, "lon": -82.1993}"#).unwrap();
+        let upper: Coord = serde_json::from_str(r#"{"LAT": 42.4421, "LON": -82.1993}"#).unwrap();
+        let array: Coord = serde_json::from_str("[-82.1993, 42.4421]").unwrap();
+        assert_eq!(object, Coord { lon: -82.1993, lat: 42.4421 });
+        assert_eq!(upper, object);
+        assert_eq!(array, object);
+    }
+
+    #[test]
+    fn coord_deserialization_validates_ranges() {
+        let error = serde_json::from_str::<Coord>(r#"{"lat": 91.0, "lon": 0.0}"#).unwrap_err();
+        assert!(error.to_string().contains("Latitude"));
+        let error = serde_json::from_str::<Coord>("[181.0, 0.0]").unwrap_err();
+        assert!(error.to_string().contains("Longitude"));
+    }
+
+    #[test]
+    fn long_names_round_trip() {
+        #[derive(Serialize, Deserialize)]
+        struct Station {
+            name: String,
+            #[serde(flatten, with = "long_names")]
+            coord: Coord,
+        }
+
+        let json = r#"{"name":"Chatham","latitude":42.4421,"longitude":-82.1993}"#;
+        let station: Station = serde_json::from_str(json).unwrap();
+        assert_eq!(station.coord, Coord { lon: -82.1993, lat: 42.4421 });
+        assert_eq!(serde_json::to_string(&station).unwrap(), json);
+
+        assert!(serde_json::from_str::<Station>(r#"{"name":"x","latitude":-95.0,"longitude":0.0}"#).is_err());
+    }
+
+    #[test]
+    fn array_helpers_use_their_own_order() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Positions {
+            #[serde(with = "lon_lat_array")]
+            geojson: Coord,
+            #[serde(with = "lat_lon_array")]
+            route: Coord,
+        }
+
+        let json = r#"{"geojson":[-82.1993,42.4421],"route":[42.4421,-82.1993]}"#;
+        let positions: Positions = serde_json::from_str(json).unwrap();
+        assert_eq!(positions.geojson, Coord { lon: -82.1993, lat: 42.4421 });
+        assert_eq!(positions.route, positions.geojson);
+        assert_eq!(serde_json::to_string(&positions).unwrap(), json);
+
+        let swapped = r#"{"geojson":[0.0,0.0],"route":[-122.4194,37.7749]}"#;
+        assert!(serde_json::from_str::<Positions>(swapped).unwrap_err().to_string().contains("Latitude"));
+    }
+}
@@ -0,0 +1,132 @@
+use super::types::{DaySummary, HistoryItem, HistoryResponse, TimeMachineResponse};
+use crate::calendar;
+use crate::client::OpenWeatherClient;
+use crate::query::WeatherQuery;
+use crate::geo::Coord;
+
+const HISTORY_API_BASE_URL: &str = "https://history.openweathermap.org/data/2.5/history/city";
+const TIMEMACHINE_API_BASE_URL: &str = "https://api.openweathermap.org/data/3.0/onecall/timemachine";
+const DAY_SUMMARY_API_BASE_URL: &str = "https://api.openweathermap.org/data/3.0/onecall/day_summary";
+
+/// The History API returns at most one week of hourly data per call.
+const HISTORY_MAX_RANGE_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// A client for retrieving past weather for a location.
+///
+/// Supports the History API (hourly observations over a time range) as well as the
+/// One Call `timemachine` (weather at a single timestamp) and `day_summary` endpoints.
+///
+/// # Fields
+/// - `client`: The shared client that sends the requests (units and API key).
+/// - `coord`: Coordinates the history is requested for.
+pub struct HistoryClient {
+    client: OpenWeatherClient,
+    coord: Coord,
+}
+
+impl HistoryClient {
+    pub fn new(coord: impl Into<Coord>, units: String, api_key: String) -> Self {
+        Self::with_client(coord, OpenWeatherClient::new(units, api_key))
+    }
+
+    /// Creates a client that shares the connection pool, units and API key of `client`.
+    pub fn with_client(coord: impl Into<Coord>, client: OpenWeatherClient) -> Self {
+        Self {
+            client,
+            coord: coord.into(),
+        }
+    }
+
+    /// Fetches hourly observations between `start` and `end` (unix, UTC).
+    ///
+    /// Ranges longer than one week are split into consecutive one-week calls. The pages
+    /// are merged into a single series sorted by time, with duplicate timestamps removed.
+    pub async fn get_hourly_history(&self, start: i64, end: i64) -> Result<Vec<HistoryItem>, Box<dyn std::error::Error>> {
+        if start > end {
+            return Err("History start must not be after end".into());
+        }
+
+        let mut items = Vec::new();
+        for (page_start, page_end) in history_pages(start, end) {
+            let mut query = self.base_query();
+            query.push(("type", "hour".to_string()));
+            query.push(("start", page_start.to_string()));
+            query.push(("end", page_end.to_string()));
+
+            let page: HistoryResponse = self.client.send_request(HISTORY_API_BASE_URL, query).await?.json().await?;
+            items.extend(page.list);
+        }
+
+        items.sort_by_key(|item| item.dt);
+        items.dedup_by_key(|item| item.dt);
+        Ok(items)
+    }
+
+    /// Fetches the weather at a single past timestamp `dt` (unix, UTC).
+    pub async fn get_timemachine(&self, dt: i64) -> Result<TimeMachineResponse, Box<dyn std::error::Error>> {
+        let mut query = self.base_query();
+        query.push(("dt", dt.to_string()));
+
+        Ok(self.client.send_request(TIMEMACHINE_API_BASE_URL, query).await?.json().await?)
+    }
+
+    /// Fetches the aggregated weather for one date.
+    pub async fn get_day_summary(&self, year: i32, month: u32, day: u32) -> Result<DaySummary, Box<dyn std::error::Error>> {
+        calendar::checked_days_from_civil(year as i64, month as i64, day as i64)?;
+
+        let mut query = self.base_query();
+        query.push(("date", format!("{:04}-{:02}-{:02}", year, month, day)));
+
+        Ok(self.client.send_request(DAY_SUMMARY_API_BASE_URL, query).await?.json().await?)
+    }
+
+    fn base_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = WeatherQuery::Coord(self.coord.clone()).to_query();
+        query.push(("units", self.client.get_units()));
+        query
+    }
+}
+
+/// Splits `start..=end` into consecutive ranges of at most one week, each starting where
+/// the previous one ended.
+fn history_pages(start: i64, end: i64) -> Vec<(i64, i64)> {
+    let mut pages = Vec::new();
+    let mut page_start = start;
+    loop {
+        let page_end = page_start.saturating_add(HISTORY_MAX_RANGE_SECONDS).min(end);
+        pages.push((page_start, page_end));
+
+        if page_end >= end {
+            return pages;
+        }
+        page_start = page_end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_single_instant_is_one_page() {
+        assert_eq!(history_pages(1_000, 1_000), vec![(1_000, 1_000)]);
+    }
+
+    #[test]
+    fn exactly_one_week_is_one_page() {
+        assert_eq!(history_pages(0, HISTORY_MAX_RANGE_SECONDS), vec![(0, HISTORY_MAX_RANGE_SECONDS)]);
+    }
+
+    #[test]
+    fn one_second_over_a_week_adds_a_page() {
+        assert_eq!(
+            history_pages(0, HISTORY_MAX_RANGE_SECONDS + 1),
+            vec![(0, HISTORY_MAX_RANGE_SECONDS), (HISTORY_MAX_RANGE_SECONDS, HISTORY_MAX_RANGE_SECONDS + 1)]
+        );
+    }
+
+    #[test]
+    fn pages_near_the_end_of_time_do_not_overflow() {
+        assert_eq!(history_pages(i64::MAX - 1, i64::MAX), vec![(i64::MAX - 1, i64::MAX)]);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod types;
+pub mod client;
+
+pub use types::*;
+pub use client::HistoryClient;
@@ -0,0 +1,279 @@
+use serde::{Deserialize, Serialize};
+
+use crate::weather::types::deserialize_rounded_option;
+use crate::weather::{Clouds, Coord, Main, Precipitation, Weather, Wind};
+
+// region: HistoryItem
+
+/// Represents one hourly observation returned by the History API.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "dt": 1752447600,
+///     "main": {
+///         "temp": 27.12,
+///         "feels_like": 28.7,
+///         "pressure": 1014,
+///         "humidity": 64,
+///         "temp_min": 26.4,
+///         "temp_max": 28.03
+///     },
+///     "wind": {
+///         "speed": 3.1,
+///         "deg": 210
+///     },
+///     "clouds": {
+///         "all": 20
+///     },
+///     "weather": [
+///         {
+///             "id": 801,
+///             "main": "Clouds",
+///             "description": "few clouds",
+///             "icon": "02d"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HistoryItem {
+    /// Time of the observation, unix, UTC
+    pub dt: i64,
+    /// Main weather measurements (temperature, pressure, humidity, etc.)
+    pub main: Main,
+    /// Wind information
+    pub wind: Wind,
+    /// Cloud coverage information
+    pub clouds: Clouds,
+    /// Weather condition information (can be multiple conditions)
+    pub weather: Vec<Weather>,
+    /// Rain volume (optional)
+    pub rain: Option<Precipitation>,
+    /// Snow volume (optional)
+    pub snow: Option<Precipitation>,
+}
+
+/// Represents one page of the History API response (at most one week of data).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HistoryResponse {
+    /// City ID the observations belong to
+    pub city_id: i64,
+    /// Number of observations returned
+    pub cnt: i32,
+    /// Hourly observations
+    pub list: Vec<HistoryItem>,
+}
+
+// endregion
+
+// region: TimeMachine
+
+/// Represents the weather at one timestamp returned by the One Call `timemachine` endpoint.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "dt": 1752449935,
+///     "sunrise": 1752401008,
+///     "sunset": 1752455116,
+///     "temp": 27.77,
+///     "feels_like": 29.32,
+///     "pressure": 1014,
+///     "humidity": 62,
+///     "dew_point": 19.8,
+///     "uvi": 1.12,
+///     "clouds": 20,
+///     "visibility": 10000,
+///     "wind_speed": 3.6,
+///     "wind_deg": 220,
+///     "weather": [
+///         {
+///             "id": 801,
+///             "main": "Clouds",
+///             "description": "few clouds",
+///             "icon": "02d"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TimeMachineData {
+    /// Requested time, unix, UTC
+    pub dt: i64,
+    /// Sunrise time, unix, UTC (absent during polar day and night)
+    pub sunrise: Option<i64>,
+    /// Sunset time, unix, UTC (absent during polar day and night)
+    pub sunset: Option<i64>,
+    /// Temperature
+    pub temp: f64,
+    /// Temperature perception by humans
+    pub feels_like: f64,
+    /// Atmospheric pressure on the sea level in hPa (optional)
+    #[serde(default, deserialize_with = "deserialize_rounded_option")]
+    pub pressure: Option<i32>,
+    /// Humidity percentage (optional)
+    #[serde(default, deserialize_with = "deserialize_rounded_option")]
+    pub humidity: Option<i32>,
+    /// Dew point temperature
+    pub dew_point: Option<f64>,
+    /// UV index (optional)
+    pub uvi: Option<f64>,
+    /// Cloudiness, % (optional)
+    #[serde(default, deserialize_with = "deserialize_rounded_option")]
+    pub clouds: Option<i32>,
+    /// Visibility in meters (optional)
+    pub visibility: Option<i64>,
+    /// Wind speed (units vary by API request: m/s for metric, mph for imperial)
+    pub wind_speed: f64,
+    /// Wind direction in degrees (meteorological), if reported
+    #[serde(default, deserialize_with = "deserialize_rounded_option")]
+    pub wind_deg: Option<i32>,
+    /// Wind gust speed (optional, same units as wind_speed)
+    pub wind_gust: Option<f64>,
+    /// Weather condition information (can be multiple conditions)
+    pub weather: Vec<Weather>,
+    /// Rain volume (optional)
+    pub rain: Option<Precipitation>,
+    /// Snow volume (optional)
+    pub snow: Option<Precipitation>,
+}
+
+/// Represents the One Call `timemachine` response.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TimeMachineResponse {
+    /// Coordinates of the location (`lat`/`lon` at the top level of the JSON object)
+    #[serde(flatten)]
+    pub coord: Coord,
+    /// Timezone name (e.g. "America/Toronto")
+    pub timezone: String,
+    /// Timezone shift in seconds from UTC
+    pub timezone_offset: i32,
+    /// Weather at the requested time
+    pub data: Vec<TimeMachineData>,
+}
+
+// endregion
+
+// region: DaySummary
+
+/// Represents a single value measured in the afternoon (12:00 local time).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AfternoonValue {
+    /// Value at 12:00
+    pub afternoon: f64,
+}
+
+/// Represents the total precipitation of the day, mm.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DailyPrecipitation {
+    /// Total amount of liquid water equivalent of precipitation, mm
+    pub total: f64,
+}
+
+/// Represents the temperatures of the day.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DaySummaryTemperature {
+    /// Minimum temperature of the day
+    pub min: f64,
+    /// Maximum temperature of the day
+    pub max: f64,
+    /// Temperature at 12:00
+    pub afternoon: f64,
+    /// Temperature at 00:00
+    pub night: f64,
+    /// Temperature at 18:00
+    pub evening: f64,
+    /// Temperature at 06:00
+    pub morning: f64,
+}
+
+/// Represents the maximum wind of the day.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DaySummaryWindMax {
+    /// Maximum wind speed
+    pub speed: f64,
+    /// Wind direction at the moment of maximum speed, degrees (meteorological)
+    pub direction: f64,
+}
+
+/// Represents the wind of the day.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DaySummaryWind {
+    /// Maximum wind of the day
+    pub max: DaySummaryWindMax,
+}
+
+/// Represents the One Call `day_summary` response: aggregated weather for one date.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "lat": 42.4421,
+///     "lon": -82.1993,
+///     "tz": "-04:00",
+///     "date": "2025-07-13",
+///     "units": "metric",
+///     "cloud_cover": { "afternoon": 20 },
+///     "humidity": { "afternoon": 62 },
+///     "precipitation": { "total": 0 },
+///     "temperature": {
+///         "min": 18.44,
+///         "max": 29.9,
+///         "afternoon": 28.1,
+///         "night": 19.52,
+///         "evening": 26.7,
+///         "morning": 18.9
+///     },
+///     "pressure": { "afternoon": 1014 },
+///     "wind": { "max": { "speed": 6.2, "direction": 220 } }
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DaySummary {
+    /// Coordinates of the location (`lat`/`lon` at the top level of the JSON object)
+    #[serde(flatten)]
+    pub coord: Coord,
+    /// Timezone in the ±XX:XX format
+    pub tz: String,
+    /// Date of the summary, YYYY-MM-DD
+    pub date: String,
+    /// Units of measurement used in the response
+    pub units: String,
+    /// Cloud coverage, %
+    pub cloud_cover: AfternoonValue,
+    /// Relative humidity, %
+    pub humidity: AfternoonValue,
+    /// Precipitation
+    pub precipitation: DailyPrecipitation,
+    /// Temperatures of the day
+    pub temperature: DaySummaryTemperature,
+    /// Atmospheric pressure, hPa
+    pub pressure: AfternoonValue,
+    /// Wind
+    pub wind: DaySummaryWind,
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timemachine_data_tolerates_missing_fields() {
+        let json = r#"{
+            "dt": 1752449935,
+            "temp": 27.77,
+            "feels_like": 29.32,
+            "pressure": 1013.6,
+            "wind_speed": 0,
+            "weather": []
+        }"#;
+        let data: TimeMachineData = serde_json::from_str(json).unwrap();
+        assert_eq!(data.pressure, Some(1014));
+        assert_eq!(data.humidity, None);
+        assert_eq!(data.clouds, None);
+        assert_eq!(data.wind_deg, None);
+    }
+}
@@ -6,8 +6,57 @@
 //! ## Features
 //!
 //! - Current weather data retrieval
+//! - Reusable `OpenWeatherClient` that takes the place (coordinates, city, zip or ID) per call
+//! - Optional `chrono` integration for observation, sunrise and sunset times
+//! - Wind compass points, Beaufort classification and arrow glyphs
+//! - Geodesic distances, bearings and bounding boxes between coordinates
+//! - Derived metrics: dew point, heat index, humidex, wind chill, apparent temperature, absolute humidity
+//! - Hourly (4 days) and daily (16 days) forecasts
+//! - Historical weather with automatic paging over long time ranges
+//! - Weather map tiles stitched into overlay images
+//! - Statistical weather data and accumulated parameters
+//! - Solar irradiance and daily energy estimates
+//! - Private weather station registration and measurements
+//! - Agro monitoring: field polygons, soil data and vegetation indices
+//! - Road risk: weather, road state and alerts along a route
+//! - Fire Weather Index and danger ratings
+//! - Offline sunrise, sunset, twilight and solar position calculations
+//! - Offline moon phase, illumination, moonrise and moonset calculations
 //! - Location-based weather lookups
+//! - Direct (city name) and reverse geocoding
+//! - Current, forecast and historical air pollution with Air Quality Index
+//! - Country and postal code validation before any request is sent
+//! - Typed JavaScript classes and TypeScript definitions for WebAssembly (`wasm` feature)
+//! - Pluggable diagnostic logging, silent by default
+//! - Batch weather lookups with bounded concurrency
 //! - Fully typed API responses
 
+pub mod agro;
+pub mod air_pollution;
+pub mod astronomy;
+pub mod batch;
+mod calendar;
+pub mod client;
+pub mod error;
+pub mod fire;
+pub mod forecast;
+pub mod geo;
+pub mod history;
 pub mod location;
+pub mod logging;
+pub mod maps;
+pub mod query;
+pub mod road_risk;
+mod scale;
+pub mod solar;
+pub mod stations;
+pub mod statistics;
+pub mod units;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 pub mod weather;
+
+pub use client::OpenWeatherClient;
+pub use error::ApiError;
+pub use query::WeatherQuery;
+pub use units::Units;
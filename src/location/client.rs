@@ -1,81 +1,86 @@
-﻿use super::types::Location;
-
-// https://api.openweathermap.org/geo/1.0/zip?zip=N7L,CA&appid={api_key}
-
-const GEOCODING_API_BASE_URL: &str = "https://api.openweathermap.org/geo/1.0/zip";
+﻿use super::country::CountryCode;
+use super::postal::PostalCode;
+use super::types::Location;
+use crate::client::OpenWeatherClient;
 
 /// A client for accessing location data via a geocoding API using a zip code and country code.
 ///
-/// This struct encapsulates an HTTP client and required parameters such as the zip code,
-/// country code, and API key. It provides methods to update these parameters and an async
-/// method to fetch location information from the remote API.
+/// This struct is a thin wrapper around [`OpenWeatherClient::geocode_zip`] bound to one zip
+/// code and country. It provides methods to update these parameters and an async method to
+/// fetch location information from the remote API.
+///
+/// The zip and country codes are validated when they are set (see [`CountryCode`] and
+/// [`PostalCode`]), so malformed input is rejected before any request is sent.
 ///
 /// # Fields
-/// - `client`: The HTTP client used to send requests.
-/// - `api_key`: API key for authenticating requests.
-/// - `zip`: Zip code for the location query.
-/// - `country`: Country code for the location query.
+/// - `client`: The shared client that sends the requests.
+/// - `postal_code`: Zip code and country for the location query.
 pub struct LocationClient {
-    client: reqwest::Client,
-    api_key: String,
-    zip: String,
-    country: String,
+    client: OpenWeatherClient,
+    postal_code: PostalCode,
 }
 
 impl LocationClient {
-    pub fn new(zip: String, country: String, api_key: String) -> Self {
+    pub fn new(zip: String, country: String, api_key: String) -> Result<Self, String> {
+        let country = CountryCode::new(&country)?;
+        let postal_code = PostalCode::new(&zip, &country)?;
+        Ok(Self::from_postal_code(postal_code, api_key))
+    }
+
+    pub fn from_postal_code(postal_code: PostalCode, api_key: String) -> Self {
+        Self::with_client(postal_code, OpenWeatherClient::new("standard".to_string(), api_key))
+    }
+
+    /// Creates a client that shares the connection pool and API key of `client`.
+    pub fn with_client(postal_code: PostalCode, client: OpenWeatherClient) -> Self {
         Self {
-            client: reqwest::Client::new(),
-            zip,
-            country,
-            api_key,
+            client,
+            postal_code,
         }
     }
-    
-    pub fn set_zip(&mut self, zip: String){
-        self.zip = zip;
+
+    /// Sets the zip code, validated against the current country.
+    pub fn set_zip(&mut self, zip: String) -> Result<(), String> {
+        self.postal_code = PostalCode::new(&zip, &self.postal_code.country())?;
+        Ok(())
     }
     
     pub fn get_zip(&self) -> String {
-        self.zip.clone()
+        self.postal_code.code().to_string()
     }
 
-    pub fn set_country(&mut self, country: String){
-        self.country = country;
+    /// Sets the country, re-validating the current zip code against it.
+    pub fn set_country(&mut self, country: String) -> Result<(), String> {
+        let country = CountryCode::new(&country)?;
+        self.postal_code = PostalCode::new(self.postal_code.code(), &country)?;
+        Ok(())
     }
     
     pub fn get_country(&self) -> String {
-        self.country.clone()
+        self.postal_code.country().alpha2().to_string()
     }
 
-    pub fn set_api_key(&mut self, api_key: String){
-        self.api_key = api_key;
+    pub fn set_postal_code(&mut self, postal_code: PostalCode) {
+        self.postal_code = postal_code;
     }
 
-    pub async fn get_location(&self) -> Result<Location, Box<dyn std::error::Error>> {
-        let zip = format!("{},{}", &self.zip, &self.country);
-
-        let response = self.client
-            .get(GEOCODING_API_BASE_URL)
-            .query(&[
-                ("zip", &zip),
-                ("appid", &self.api_key)
-            ])
-            .send()
-            .await?;
+    pub fn get_postal_code(&self) -> PostalCode {
+        self.postal_code.clone()
+    }
 
-        if !response.status().is_success() {
-            return Err(format!("API request failed with status: {}", response.status()).into());
-        }
+    pub fn set_api_key(&mut self, api_key: String){
+        self.client.set_api_key(api_key);
+    }
 
-        Ok(response.json().await?)
+    pub async fn get_location(&self) -> Result<Location, Box<dyn std::error::Error>> {
+        self.client.geocode_zip(&self.postal_code).await
     }
 
     pub fn detailed_display(&self) -> String {
         format!(
             r#"country: [{}] - zip: [{}]"#,
-            self.country,
-            self.zip,
+            self.postal_code.country(),
+            self.postal_code.code(),
         )
     }
 }
\ No newline at end of file
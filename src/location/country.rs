@@ -0,0 +1,404 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents a country from the ISO 3166-1 standard.
+///
+/// Values can only be obtained from the built-in table, so a `CountryCode` is always valid.
+/// It serializes as its alpha-2 code, which is what the geocoding API expects.
+///
+/// Sample JSON
+/// ```json
+/// "CA"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct CountryCode {
+    alpha2: &'static str,
+    alpha3: &'static str,
+    numeric: u16,
+    name: &'static str,
+}
+
+impl CountryCode {
+    const fn entry(alpha2: &'static str, alpha3: &'static str, numeric: u16, name: &'static str) -> Self {
+        CountryCode { alpha2, alpha3, numeric, name }
+    }
+
+    /// Parses an alpha-2 (`CA`) or alpha-3 (`CAN`) code, ignoring case and surrounding whitespace.
+    pub fn new(code: &str) -> Result<Self, String> {
+        let code = code.trim().to_ascii_uppercase();
+        let found = match code.len() {
+            2 => Self::from_alpha2(&code),
+            3 => Self::from_alpha3(&code),
+            _ => None,
+        };
+
+        found.ok_or_else(|| format!("Unknown ISO 3166-1 country code: [{}]", code))
+    }
+
+    /// Looks up a country by its alpha-2 code (case-sensitive, uppercase).
+    pub fn from_alpha2(alpha2: &str) -> Option<Self> {
+        COUNTRIES.iter().find(|country| country.alpha2 == alpha2).copied()
+    }
+
+    /// Looks up a country by its alpha-3 code (case-sensitive, uppercase).
+    pub fn from_alpha3(alpha3: &str) -> Option<Self> {
+        COUNTRIES.iter().find(|country| country.alpha3 == alpha3).copied()
+    }
+
+    /// Looks up a country by its numeric code.
+    pub fn from_numeric(numeric: u16) -> Option<Self> {
+        COUNTRIES.iter().find(|country| country.numeric == numeric).copied()
+    }
+
+    /// Every country of the standard, ordered by alpha-2 code.
+    pub fn all() -> &'static [CountryCode] {
+        &COUNTRIES
+    }
+
+    /// Two-letter code (e.g. `CA`)
+    pub fn alpha2(&self) -> &'static str {
+        self.alpha2
+    }
+
+    /// Three-letter code (e.g. `CAN`)
+    pub fn alpha3(&self) -> &'static str {
+        self.alpha3
+    }
+
+    /// Numeric code (e.g. `124`)
+    pub fn numeric(&self) -> u16 {
+        self.numeric
+    }
+
+    /// English short name (e.g. `Canada`)
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.alpha2)
+    }
+}
+
+impl FromStr for CountryCode {
+    type Err = String;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        CountryCode::new(code)
+    }
+}
+
+impl TryFrom<String> for CountryCode {
+    type Error = String;
+
+    fn try_from(code: String) -> Result<Self, Self::Error> {
+        CountryCode::new(&code)
+    }
+}
+
+impl From<CountryCode> for String {
+    fn from(country: CountryCode) -> Self {
+        country.alpha2.to_string()
+    }
+}
+
+static COUNTRIES: [CountryCode; 249] = [
+    CountryCode::entry("AD", "AND", 20, "Andorra"),
+    CountryCode::entry("AE", "ARE", 784, "United Arab Emirates"),
+    CountryCode::entry("AF", "AFG", 4, "Afghanistan"),
+    CountryCode::entry("AG", "ATG", 28, "Antigua and Barbuda"),
+    CountryCode::entry("AI", "AIA", 660, "Anguilla"),
+    CountryCode::entry("AL", "ALB", 8, "Albania"),
+    CountryCode::entry("AM", "ARM", 51, "Armenia"),
+    CountryCode::entry("AO", "AGO", 24, "Angola"),
+    CountryCode::entry("AQ", "ATA", 10, "Antarctica"),
+    CountryCode::entry("AR", "ARG", 32, "Argentina"),
+    CountryCode::entry("AS", "ASM", 16, "American Samoa"),
+    CountryCode::entry("AT", "AUT", 40, "Austria"),
+    CountryCode::entry("AU", "AUS", 36, "Australia"),
+    CountryCode::entry("AW", "ABW", 533, "Aruba"),
+    CountryCode::entry("AX", "ALA", 248, "Åland Islands"),
+    CountryCode::entry("AZ", "AZE", 31, "Azerbaijan"),
+    CountryCode::entry("BA", "BIH", 70, "Bosnia and Herzegovina"),
+    CountryCode::entry("BB", "BRB", 52, "Barbados"),
+    CountryCode::entry("BD", "BGD", 50, "Bangladesh"),
+    CountryCode::entry("BE", "BEL", 56, "Belgium"),
+    CountryCode::entry("BF", "BFA", 854, "Burkina Faso"),
+    CountryCode::entry("BG", "BGR", 100, "Bulgaria"),
+    CountryCode::entry("BH", "BHR", 48, "Bahrain"),
+    CountryCode::entry("BI", "BDI", 108, "Burundi"),
+    CountryCode::entry("BJ", "BEN", 204, "Benin"),
+    CountryCode::entry("BL", "BLM", 652, "Saint Barthélemy"),
+    CountryCode::entry("BM", "BMU", 60, "Bermuda"),
+    CountryCode::entry("BN", "BRN", 96, "Brunei Darussalam"),
+    CountryCode::entry("BO", "BOL", 68, "Bolivia, Plurinational State of"),
+    CountryCode::entry("BQ", "BES", 535, "Bonaire, Sint Eustatius and Saba"),
+    CountryCode::entry("BR", "BRA", 76, "Brazil"),
+    CountryCode::entry("BS", "BHS", 44, "Bahamas"),
+    CountryCode::entry("BT", "BTN", 64, "Bhutan"),
+    CountryCode::entry("BV", "BVT", 74, "Bouvet Island"),
+    CountryCode::entry("BW", "BWA", 72, "Botswana"),
+    CountryCode::entry("BY", "BLR", 112, "Belarus"),
+    CountryCode::entry("BZ", "BLZ", 84, "Belize"),
+    CountryCode::entry("CA", "CAN", 124, "Canada"),
+    CountryCode::entry("CC", "CCK", 166, "Cocos (Keeling) Islands"),
+    CountryCode::entry("CD", "COD", 180, "Congo, The Democratic Republic of the"),
+    CountryCode::entry("CF", "CAF", 140, "Central African Republic"),
+    CountryCode::entry("CG", "COG", 178, "Congo"),
+    CountryCode::entry("CH", "CHE", 756, "Switzerland"),
+    CountryCode::entry("CI", "CIV", 384, "Côte d'Ivoire"),
+    CountryCode::entry("CK", "COK", 184, "Cook Islands"),
+    CountryCode::entry("CL", "CHL", 152, "Chile"),
+    CountryCode::entry("CM", "CMR", 120, "Cameroon"),
+    CountryCode::entry("CN", "CHN", 156, "China"),
+    CountryCode::entry("CO", "COL", 170, "Colombia"),
+    CountryCode::entry("CR", "CRI", 188, "Costa Rica"),
+    CountryCode::entry("CU", "CUB", 192, "Cuba"),
+    CountryCode::entry("CV", "CPV", 132, "Cabo Verde"),
+    CountryCode::entry("CW", "CUW", 531, "Curaçao"),
+    CountryCode::entry("CX", "CXR", 162, "Christmas Island"),
+    CountryCode::entry("CY", "CYP", 196, "Cyprus"),
+    CountryCode::entry("CZ", "CZE", 203, "Czechia"),
+    CountryCode::entry("DE", "DEU", 276, "Germany"),
+    CountryCode::entry("DJ", "DJI", 262, "Djibouti"),
+    CountryCode::entry("DK", "DNK", 208, "Denmark"),
+    CountryCode::entry("DM", "DMA", 212, "Dominica"),
+    CountryCode::entry("DO", "DOM", 214, "Dominican Republic"),
+    CountryCode::entry("DZ", "DZA", 12, "Algeria"),
+    CountryCode::entry("EC", "ECU", 218, "Ecuador"),
+    CountryCode::entry("EE", "EST", 233, "Estonia"),
+    CountryCode::entry("EG", "EGY", 818, "Egypt"),
+    CountryCode::entry("EH", "ESH", 732, "Western Sahara"),
+    CountryCode::entry("ER", "ERI", 232, "Eritrea"),
+    CountryCode::entry("ES", "ESP", 724, "Spain"),
+    CountryCode::entry("ET", "ETH", 231, "Ethiopia"),
+    CountryCode::entry("FI", "FIN", 246, "Finland"),
+    CountryCode::entry("FJ", "FJI", 242, "Fiji"),
+    CountryCode::entry("FK", "FLK", 238, "Falkland Islands (Malvinas)"),
+    CountryCode::entry("FM", "FSM", 583, "Micronesia, Federated States of"),
+    CountryCode::entry("FO", "FRO", 234, "Faroe Islands"),
+    CountryCode::entry("FR", "FRA", 250, "France"),
+    CountryCode::entry("GA", "GAB", 266, "Gabon"),
+    CountryCode::entry("GB", "GBR", 826, "United Kingdom"),
+    CountryCode::entry("GD", "GRD", 308, "Grenada"),
+    CountryCode::entry("GE", "GEO", 268, "Georgia"),
+    CountryCode::entry("GF", "GUF", 254, "French Guiana"),
+    CountryCode::entry("GG", "GGY", 831, "Guernsey"),
+    CountryCode::entry("GH", "GHA", 288, "Ghana"),
+    CountryCode::entry("GI", "GIB", 292, "Gibraltar"),
+    CountryCode::entry("GL", "GRL", 304, "Greenland"),
+    CountryCode::entry("GM", "GMB", 270, "Gambia"),
+    CountryCode::entry("GN", "GIN", 324, "Guinea"),
+    CountryCode::entry("GP", "GLP", 312, "Guadeloupe"),
+    CountryCode::entry("GQ", "GNQ", 226, "Equatorial Guinea"),
+    CountryCode::entry("GR", "GRC", 300, "Greece"),
+    CountryCode::entry("GS", "SGS", 239, "South Georgia and the South Sandwich Islands"),
+    CountryCode::entry("GT", "GTM", 320, "Guatemala"),
+    CountryCode::entry("GU", "GUM", 316, "Guam"),
+    CountryCode::entry("GW", "GNB", 624, "Guinea-Bissau"),
+    CountryCode::entry("GY", "GUY", 328, "Guyana"),
+    CountryCode::entry("HK", "HKG", 344, "Hong Kong"),
+    CountryCode::entry("HM", "HMD", 334, "Heard Island and McDonald Islands"),
+    CountryCode::entry("HN", "HND", 340, "Honduras"),
+    CountryCode::entry("HR", "HRV", 191, "Croatia"),
+    CountryCode::entry("HT", "HTI", 332, "Haiti"),
+    CountryCode::entry("HU", "HUN", 348, "Hungary"),
+    CountryCode::entry("ID", "IDN", 360, "Indonesia"),
+    CountryCode::entry("IE", "IRL", 372, "Ireland"),
+    CountryCode::entry("IL", "ISR", 376, "Israel"),
+    CountryCode::entry("IM", "IMN", 833, "Isle of Man"),
+    CountryCode::entry("IN", "IND", 356, "India"),
+    CountryCode::entry("IO", "IOT", 86, "British Indian Ocean Territory"),
+    CountryCode::entry("IQ", "IRQ", 368, "Iraq"),
+    CountryCode::entry("IR", "IRN", 364, "Iran, Islamic Republic of"),
+    CountryCode::entry("IS", "ISL", 352, "Iceland"),
+    CountryCode::entry("IT", "ITA", 380, "Italy"),
+    CountryCode::entry("JE", "JEY", 832, "Jersey"),
+    CountryCode::entry("JM", "JAM", 388, "Jamaica"),
+    CountryCode::entry("JO", "JOR", 400, "Jordan"),
+    CountryCode::entry("JP", "JPN", 392, "Japan"),
+    CountryCode::entry("KE", "KEN", 404, "Kenya"),
+    CountryCode::entry("KG", "KGZ", 417, "Kyrgyzstan"),
+    CountryCode::entry("KH", "KHM", 116, "Cambodia"),
+    CountryCode::entry("KI", "KIR", 296, "Kiribati"),
+    CountryCode::entry("KM", "COM", 174, "Comoros"),
+    CountryCode::entry("KN", "KNA", 659, "Saint Kitts and Nevis"),
+    CountryCode::entry("KP", "PRK", 408, "Korea, Democratic People's Republic of"),
+    CountryCode::entry("KR", "KOR", 410, "Korea, Republic of"),
+    CountryCode::entry("KW", "KWT", 414, "Kuwait"),
+    CountryCode::entry("KY", "CYM", 136, "Cayman Islands"),
+    CountryCode::entry("KZ", "KAZ", 398, "Kazakhstan"),
+    CountryCode::entry("LA", "LAO", 418, "Lao People's Democratic Republic"),
+    CountryCode::entry("LB", "LBN", 422, "Lebanon"),
+    CountryCode::entry("LC", "LCA", 662, "Saint Lucia"),
+    CountryCode::entry("LI", "LIE", 438, "Liechtenstein"),
+    CountryCode::entry("LK", "LKA", 144, "Sri Lanka"),
+    CountryCode::entry("LR", "LBR", 430, "Liberia"),
+    CountryCode::entry("LS", "LSO", 426, "Lesotho"),
+    CountryCode::entry("LT", "LTU", 440, "Lithuania"),
+    CountryCode::entry("LU", "LUX", 442, "Luxembourg"),
+    CountryCode::entry("LV", "LVA", 428, "Latvia"),
+    CountryCode::entry("LY", "LBY", 434, "Libya"),
+    CountryCode::entry("MA", "MAR", 504, "Morocco"),
+    CountryCode::entry("MC", "MCO", 492, "Monaco"),
+    CountryCode::entry("MD", "MDA", 498, "Moldova, Republic of"),
+    CountryCode::entry("ME", "MNE", 499, "Montenegro"),
+    CountryCode::entry("MF", "MAF", 663, "Saint Martin (French part)"),
+    CountryCode::entry("MG", "MDG", 450, "Madagascar"),
+    CountryCode::entry("MH", "MHL", 584, "Marshall Islands"),
+    CountryCode::entry("MK", "MKD", 807, "North Macedonia"),
+    CountryCode::entry("ML", "MLI", 466, "Mali"),
+    CountryCode::entry("MM", "MMR", 104, "Myanmar"),
+    CountryCode::entry("MN", "MNG", 496, "Mongolia"),
+    CountryCode::entry("MO", "MAC", 446, "Macao"),
+    CountryCode::entry("MP", "MNP", 580, "Northern Mariana Islands"),
+    CountryCode::entry("MQ", "MTQ", 474, "Martinique"),
+    CountryCode::entry("MR", "MRT", 478, "Mauritania"),
+    CountryCode::entry("MS", "MSR", 500, "Montserrat"),
+    CountryCode::entry("MT", "MLT", 470, "Malta"),
+    CountryCode::entry("MU", "MUS", 480, "Mauritius"),
+    CountryCode::entry("MV", "MDV", 462, "Maldives"),
+    CountryCode::entry("MW", "MWI", 454, "Malawi"),
+    CountryCode::entry("MX", "MEX", 484, "Mexico"),
+    CountryCode::entry("MY", "MYS", 458, "Malaysia"),
+    CountryCode::entry("MZ", "MOZ", 508, "Mozambique"),
+    CountryCode::entry("NA", "NAM", 516, "Namibia"),
+    CountryCode::entry("NC", "NCL", 540, "New Caledonia"),
+    CountryCode::entry("NE", "NER", 562, "Niger"),
+    CountryCode::entry("NF", "NFK", 574, "Norfolk Island"),
+    CountryCode::entry("NG", "NGA", 566, "Nigeria"),
+    CountryCode::entry("NI", "NIC", 558, "Nicaragua"),
+    CountryCode::entry("NL", "NLD", 528, "Netherlands"),
+    CountryCode::entry("NO", "NOR", 578, "Norway"),
+    CountryCode::entry("NP", "NPL", 524, "Nepal"),
+    CountryCode::entry("NR", "NRU", 520, "Nauru"),
+    CountryCode::entry("NU", "NIU", 570, "Niue"),
+    CountryCode::entry("NZ", "NZL", 554, "New Zealand"),
+    CountryCode::entry("OM", "OMN", 512, "Oman"),
+    CountryCode::entry("PA", "PAN", 591, "Panama"),
+    CountryCode::entry("PE", "PER", 604, "Peru"),
+    CountryCode::entry("PF", "PYF", 258, "French Polynesia"),
+    CountryCode::entry("PG", "PNG", 598, "Papua New Guinea"),
+    CountryCode::entry("PH", "PHL", 608, "Philippines"),
+    CountryCode::entry("PK", "PAK", 586, "Pakistan"),
+    CountryCode::entry("PL", "POL", 616, "Poland"),
+    CountryCode::entry("PM", "SPM", 666, "Saint Pierre and Miquelon"),
+    CountryCode::entry("PN", "PCN", 612, "Pitcairn"),
+    CountryCode::entry("PR", "PRI", 630, "Puerto Rico"),
+    CountryCode::entry("PS", "PSE", 275, "Palestine, State of"),
+    CountryCode::entry("PT", "PRT", 620, "Portugal"),
+    CountryCode::entry("PW", "PLW", 585, "Palau"),
+    CountryCode::entry("PY", "PRY", 600, "Paraguay"),
+    CountryCode::entry("QA", "QAT", 634, "Qatar"),
+    CountryCode::entry("RE", "REU", 638, "Réunion"),
+    CountryCode::entry("RO", "ROU", 642, "Romania"),
+    CountryCode::entry("RS", "SRB", 688, "Serbia"),
+    CountryCode::entry("RU", "RUS", 643, "Russian Federation"),
+    CountryCode::entry("RW", "RWA", 646, "Rwanda"),
+    CountryCode::entry("SA", "SAU", 682, "Saudi Arabia"),
+    CountryCode::entry("SB", "SLB", 90, "Solomon Islands"),
+    CountryCode::entry("SC", "SYC", 690, "Seychelles"),
+    CountryCode::entry("SD", "SDN", 729, "Sudan"),
+    CountryCode::entry("SE", "SWE", 752, "Sweden"),
+    CountryCode::entry("SG", "SGP", 702, "Singapore"),
+    CountryCode::entry("SH", "SHN", 654, "Saint Helena, Ascension and Tristan da Cunha"),
+    CountryCode::entry("SI", "SVN", 705, "Slovenia"),
+    CountryCode::entry("SJ", "SJM", 744, "Svalbard and Jan Mayen"),
+    CountryCode::entry("SK", "SVK", 703, "Slovakia"),
+    CountryCode::entry("SL", "SLE", 694, "Sierra Leone"),
+    CountryCode::entry("SM", "SMR", 674, "San Marino"),
+    CountryCode::entry("SN", "SEN", 686, "Senegal"),
+    CountryCode::entry("SO", "SOM", 706, "Somalia"),
+    CountryCode::entry("SR", "SUR", 740, "Suriname"),
+    CountryCode::entry("SS", "SSD", 728, "South Sudan"),
+    CountryCode::entry("ST", "STP", 678, "Sao Tome and Principe"),
+    CountryCode::entry("SV", "SLV", 222, "El Salvador"),
+    CountryCode::entry("SX", "SXM", 534, "Sint Maarten (Dutch part)"),
+    CountryCode::entry("SY", "SYR", 760, "Syrian Arab Republic"),
+    CountryCode::entry("SZ", "SWZ", 748, "Eswatini"),
+    CountryCode::entry("TC", "TCA", 796, "Turks and Caicos Islands"),
+    CountryCode::entry("TD", "TCD", 148, "Chad"),
+    CountryCode::entry("TF", "ATF", 260, "French Southern Territories"),
+    CountryCode::entry("TG", "TGO", 768, "Togo"),
+    CountryCode::entry("TH", "THA", 764, "Thailand"),
+    CountryCode::entry("TJ", "TJK", 762, "Tajikistan"),
+    CountryCode::entry("TK", "TKL", 772, "Tokelau"),
+    CountryCode::entry("TL", "TLS", 626, "Timor-Leste"),
+    CountryCode::entry("TM", "TKM", 795, "Turkmenistan"),
+    CountryCode::entry("TN", "TUN", 788, "Tunisia"),
+    CountryCode::entry("TO", "TON", 776, "Tonga"),
+    CountryCode::entry("TR", "TUR", 792, "Türkiye"),
+    CountryCode::entry("TT", "TTO", 780, "Trinidad and Tobago"),
+    CountryCode::entry("TV", "TUV", 798, "Tuvalu"),
+    CountryCode::entry("TW", "TWN", 158, "Taiwan, Province of China"),
+    CountryCode::entry("TZ", "TZA", 834, "Tanzania, United Republic of"),
+    CountryCode::entry("UA", "UKR", 804, "Ukraine"),
+    CountryCode::entry("UG", "UGA", 800, "Uganda"),
+    CountryCode::entry("UM", "UMI", 581, "United States Minor Outlying Islands"),
+    CountryCode::entry("US", "USA", 840, "United States"),
+    CountryCode::entry("UY", "URY", 858, "Uruguay"),
+    CountryCode::entry("UZ", "UZB", 860, "Uzbekistan"),
+    CountryCode::entry("VA", "VAT", 336, "Holy See (Vatican City State)"),
+    CountryCode::entry("VC", "VCT", 670, "Saint Vincent and the Grenadines"),
+    CountryCode::entry("VE", "VEN", 862, "Venezuela, Bolivarian Republic of"),
+    CountryCode::entry("VG", "VGB", 92, "Virgin Islands, British"),
+    CountryCode::entry("VI", "VIR", 850, "Virgin Islands, U.S."),
+    CountryCode::entry("VN", "VNM", 704, "Viet Nam"),
+    CountryCode::entry("VU", "VUT", 548, "Vanuatu"),
+    CountryCode::entry("WF", "WLF", 876, "Wallis and Futuna"),
+    CountryCode::entry("WS", "WSM", 882, "Samoa"),
+    CountryCode::entry("YE", "YEM", 887, "Yemen"),
+    CountryCode::entry("YT", "MYT", 175, "Mayotte"),
+    CountryCode::entry("ZA", "ZAF", 710, "South Africa"),
+    CountryCode::entry("ZM", "ZMB", 894, "Zambia"),
+    CountryCode::entry("ZW", "ZWE", 716, "Zimbabwe"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_is_sorted_and_unique() {
+        let countries = CountryCode::all();
+        assert_eq!(countries.len(), 249);
+        for pair in countries.windows(2) {
+            assert!(pair[0].alpha2 < pair[1].alpha2, "{} before {}", pair[0].alpha2, pair[1].alpha2);
+        }
+        for country in countries {
+            assert_eq!(CountryCode::from_alpha3(country.alpha3), Some(*country));
+            assert_eq!(CountryCode::from_numeric(country.numeric), Some(*country));
+        }
+    }
+
+    #[test]
+    fn looks_up_known_codes() {
+        let canada = CountryCode::new("ca").unwrap();
+        assert_eq!((canada.alpha2(), canada.alpha3(), canada.numeric()), ("CA", "CAN", 124));
+        assert_eq!(CountryCode::new(" CAN ").unwrap(), canada);
+        assert_eq!(CountryCode::from_numeric(826).unwrap().alpha2(), "GB");
+        assert_eq!("usa".parse::<CountryCode>().unwrap().alpha2(), "US");
+    }
+
+    #[test]
+    fn rejects_unknown_codes() {
+        assert!(CountryCode::new("XX").is_err());
+        assert!(CountryCode::new("UK").is_err());
+        assert!(CountryCode::new("Canada").is_err());
+        assert!(CountryCode::new("").is_err());
+        assert_eq!(CountryCode::from_alpha2("ca"), None);
+        assert_eq!(CountryCode::from_numeric(999), None);
+    }
+
+    #[test]
+    fn serializes_as_alpha2() {
+        let canada = CountryCode::new("CA").unwrap();
+        assert_eq!(serde_json::to_string(&canada).unwrap(), "\"CA\"");
+        assert_eq!(serde_json::from_str::<CountryCode>("\"CA\"").unwrap(), canada);
+        assert!(serde_json::from_str::<CountryCode>("\"ZZ\"").is_err());
+    }
+}
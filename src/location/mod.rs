@@ -1,5 +1,9 @@
 ﻿pub mod types;
 pub mod client;
+pub mod country;
+pub mod postal;
 
 pub use types::*;
-pub use client::LocationClient;
\ No newline at end of file
+pub use client::LocationClient;
+pub use country::CountryCode;
+pub use postal::PostalCode;
\ No newline at end of file
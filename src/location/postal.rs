@@ -0,0 +1,183 @@
+use std::fmt;
+
+use super::country::CountryCode;
+
+/// Represents a postal code that has been checked against the format of its country.
+///
+/// Countries with a known format are validated strictly and normalized (uppercase, with
+/// the separator the country uses, e.g. `N7L 3K6` or `12345-6789`). Other countries only
+/// get a basic sanity check: 1 to 10 letters, digits, spaces or dashes.
+///
+/// Several formats accept a partial code where the geocoding API does: Canadian forward
+/// sortation areas (`N7L`) and UK outward codes (`SW1A`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PostalCode {
+    code: String,
+    country: CountryCode,
+}
+
+impl PostalCode {
+    pub fn new(code: &str, country: &CountryCode) -> Result<Self, String> {
+        let code = code.trim().to_ascii_uppercase();
+
+        let code = match formats(country.alpha2()) {
+            Some(patterns) => patterns
+                .iter()
+                .find_map(|pattern| format_code(pattern, &code))
+                .ok_or_else(|| format!("Invalid postal code [{}] for {} (expected {})", code, country.name(), patterns.join(" or ")))?,
+            None => {
+                if code.is_empty()
+                    || code.len() > 10
+                    || !code.chars().all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '-')
+                {
+                    return Err(format!("Invalid postal code [{}] for {}", code, country.name()));
+                }
+                code
+            }
+        };
+
+        Ok(PostalCode { code, country: *country })
+    }
+
+    /// Normalized postal code
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Country the code belongs to
+    pub fn country(&self) -> CountryCode {
+        self.country
+    }
+}
+
+impl fmt::Display for PostalCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code)
+    }
+}
+
+/// Accepted formats per country: `9` is a digit, `A` a letter, `X` a letter or digit.
+/// Spaces and dashes are separators: optional in the input, always present in the output.
+fn formats(alpha2: &str) -> Option<&'static [&'static str]> {
+    let patterns: &'static [&'static str] = match alpha2 {
+        "US" => &["99999", "99999-9999"],
+        "CA" => &["A9A", "A9A 9A9"],
+        "GB" | "GG" | "IM" | "JE" => &[
+            "A9", "A99", "A9A", "AA9", "AA99", "AA9A",
+            "A9 9AA", "A99 9AA", "A9A 9AA", "AA9 9AA", "AA99 9AA", "AA9A 9AA",
+        ],
+        "IE" => &["A9X", "A9X XXXX"],
+        "NL" => &["9999", "9999 AA"],
+        "JP" => &["999-9999"],
+        "BR" => &["99999-999"],
+        "PL" => &["99-999"],
+        "PT" => &["9999-999"],
+        "SE" | "CZ" | "SK" | "GR" => &["999 99"],
+        "IN" | "RU" | "CN" | "SG" | "KZ" | "BY" | "RO" | "VN" => &["999999"],
+        "AR" => &["9999", "A9999AAA"],
+        "AU" | "AT" | "BE" | "BG" | "CH" | "CY" | "DK" | "GE" | "HU" | "LU" | "MK" | "NO" | "NZ"
+        | "PH" | "SI" | "TN" | "ZA" => &["9999"],
+        "DE" | "DZ" | "EE" | "ES" | "FI" | "FR" | "HR" | "ID" | "IT" | "KR" | "LT" | "MA" | "MC"
+        | "MX" | "MY" | "PK" | "SA" | "TH" | "TR" | "UA" => &["99999"],
+        _ => return None,
+    };
+    Some(patterns)
+}
+
+/// Returns `code` laid out like `pattern`, or `None` if it does not match.
+fn format_code(pattern: &str, code: &str) -> Option<String> {
+    let mut chars = code.chars().filter(|c| *c != ' ' && *c != '-');
+    let mut formatted = String::with_capacity(pattern.len());
+
+    for expected in pattern.chars() {
+        if expected == ' ' || expected == '-' {
+            formatted.push(expected);
+            continue;
+        }
+
+        let c = chars.next()?;
+        let valid = match expected {
+            '9' => c.is_ascii_digit(),
+            'A' => c.is_ascii_uppercase(),
+            _ => c.is_ascii_alphanumeric(),
+        };
+        if !valid {
+            return None;
+        }
+        formatted.push(c);
+    }
+
+    if chars.next().is_some() {
+        return None;
+    }
+    Some(formatted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn postal_code(code: &str, country: &str) -> Result<String, String> {
+        PostalCode::new(code, &CountryCode::new(country).unwrap()).map(|postal_code| postal_code.code().to_string())
+    }
+
+    #[test]
+    fn normalizes_valid_codes() {
+        let cases = [
+            ("n7l 3k6", "CA", "N7L 3K6"),
+            ("N7L3K6", "CA", "N7L 3K6"),
+            ("n7l", "CA", "N7L"),
+            ("90210", "US", "90210"),
+            ("902101234", "US", "90210-1234"),
+            ("sw1a1aa", "GB", "SW1A 1AA"),
+            ("EC1A 1BB", "GB", "EC1A 1BB"),
+            ("m1 1ae", "GB", "M1 1AE"),
+            ("D02 X285", "IE", "D02 X285"),
+            ("1012ab", "NL", "1012 AB"),
+            ("1000001", "JP", "100-0001"),
+            ("01310-100", "BR", "01310-100"),
+            ("11455", "SE", "114 55"),
+            ("75008", "FR", "75008"),
+            ("c1425abc", "AR", "C1425ABC"),
+            ("2000", "AU", "2000"),
+        ];
+        for (input, country, expected) in cases {
+            assert_eq!(postal_code(input, country).as_deref(), Ok(expected), "{} {}", input, country);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_codes() {
+        let cases = [
+            ("N7L 3K", "CA"),
+            ("7NL", "CA"),
+            ("9021", "US"),
+            ("90210-12", "US"),
+            ("ABCDE", "US"),
+            ("1SW 1AA", "GB"),
+            ("1012", "DE"),
+            ("750080", "FR"),
+            ("12345", "AU"),
+            ("", "CA"),
+        ];
+        for (input, country) in cases {
+            assert!(postal_code(input, country).is_err(), "{} {} should be rejected", input, country);
+        }
+    }
+
+    #[test]
+    fn unknown_formats_get_a_sanity_check() {
+        assert_eq!(postal_code("00100", "KE").as_deref(), Ok("00100"));
+        assert_eq!(postal_code("ab-12", "KE").as_deref(), Ok("AB-12"));
+        assert!(postal_code("", "KE").is_err());
+        assert!(postal_code("12345678901", "KE").is_err());
+        assert!(postal_code("1234#", "KE").is_err());
+    }
+
+    #[test]
+    fn keeps_its_country() {
+        let postal_code = PostalCode::new("N7L", &CountryCode::new("CA").unwrap()).unwrap();
+        assert_eq!(postal_code.country().alpha2(), "CA");
+        assert_eq!(postal_code.to_string(), "N7L");
+    }
+}
@@ -1,6 +1,9 @@
 ﻿use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 
+use crate::geo::Coord;
+
 /// Represents a geographical location identified by postal code.
 ///
 /// Contains essential information about the location including the postal code (`zip`),
@@ -23,10 +26,9 @@ pub struct Location {
     pub zip: String,
     /// City or locality name
     pub name: String,
-    /// Latitude in decimal degrees
-    pub lat: f64,
-    /// Longitude in decimal degrees
-    pub lon: f64,
+    /// Coordinates of the location (`lat`/`lon` at the top level of the JSON object)
+    #[serde(flatten)]
+    pub coord: Coord,
     /// Two-letter country code (ISO 3166-1 alpha-2)
     pub country: String,
 }
@@ -34,6 +36,80 @@ pub struct Location {
 impl fmt::Display for Location {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "name: [{}]\ncountry: [{}]\nzip: [{}]\nlat: [{}]\nlon: [{}]",
-               self.name, self.country, self.zip, self.lat, self.lon)
+               self.name, self.country, self.zip, self.coord.lat, self.coord.lon)
+    }
+}
+
+impl From<Location> for Coord {
+    fn from(location: Location) -> Self {
+        location.coord
+    }
+}
+
+impl From<&Location> for Coord {
+    fn from(location: &Location) -> Self {
+        location.coord.clone()
+    }
+}
+
+/// Represents a place returned by direct (city name) or reverse (coordinates) geocoding.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "name": "Chatham-Kent",
+///     "local_names": {
+///         "en": "Chatham-Kent",
+///         "fr": "Chatham-Kent"
+///     },
+///     "lat": 42.4048,
+///     "lon": -82.191,
+///     "country": "CA",
+///     "state": "Ontario"
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Place {
+    /// Name of the place
+    pub name: String,
+    /// Name of the place in other languages, keyed by ISO 639 language code
+    pub local_names: Option<HashMap<String, String>>,
+    /// Coordinates of the place (`lat`/`lon` at the top level of the JSON object)
+    #[serde(flatten)]
+    pub coord: Coord,
+    /// Two-letter country code (ISO 3166-1 alpha-2)
+    pub country: String,
+    /// State or region, where available
+    pub state: Option<String>,
+}
+
+impl Place {
+    /// Name of the place in `language` (ISO 639 code, e.g. `fr`), falling back to `name`.
+    pub fn local_name(&self, language: &str) -> &str {
+        self.local_names
+            .as_ref()
+            .and_then(|names| names.get(language))
+            .unwrap_or(&self.name)
+    }
+}
+
+impl fmt::Display for Place {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.state {
+            Some(state) => write!(f, "{}, {}, {}", self.name, state, self.country),
+            None => write!(f, "{}, {}", self.name, self.country),
+        }
+    }
+}
+
+impl From<Place> for Coord {
+    fn from(place: Place) -> Self {
+        place.coord
+    }
+}
+
+impl From<&Place> for Coord {
+    fn from(place: &Place) -> Self {
+        place.coord.clone()
     }
 }
@@ -0,0 +1,71 @@
+//! Pluggable diagnostic logging.
+//!
+//! The library reports what it is doing (requests sent, failures, WASM bridge steps) through
+//! a single process-wide logger. No logger is installed by default, so nothing is printed on
+//! any target until [`set_logger`] is called, e.g. with `|message| eprintln!("{}", message)`
+//! or, with the `wasm` feature, `wasm::console_logger`.
+
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+type Logger = Arc<dyn Fn(&str) + Send + Sync>;
+
+static LOGGER: RwLock<Option<Logger>> = RwLock::new(None);
+
+/// Installs `logger`, replacing the previous one. It receives one line per message.
+pub fn set_logger(logger: impl Fn(&str) + Send + Sync + 'static) {
+    if let Ok(mut current) = LOGGER.write() {
+        *current = Some(Arc::new(logger));
+    }
+}
+
+/// Removes the installed logger, silencing the library again.
+pub fn clear_logger() {
+    if let Ok(mut current) = LOGGER.write() {
+        *current = None;
+    }
+}
+
+/// Sends a message to the installed logger. The message is only formatted when a logger is set.
+///
+/// The logger is called after the lock is released, so it may itself call [`set_logger`] or
+/// [`clear_logger`].
+pub(crate) fn log(args: fmt::Arguments<'_>) {
+    let logger = match LOGGER.read() {
+        Ok(current) => current.clone(),
+        Err(_) => return,
+    };
+    if let Some(logger) = logger {
+        logger(&args.to_string());
+    }
+}
+
+/// Formats and logs a message through the installed logger, like `format!`.
+macro_rules! debug_log {
+    ($($t:tt)*) => ($crate::logging::log(format_args!($($t)*)))
+}
+
+pub(crate) use debug_log;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn logger_can_replace_itself() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        set_logger(|_| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            clear_logger();
+        });
+        debug_log!("first");
+        debug_log!("second");
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+        set_logger(|_| set_logger(|_| {}));
+        debug_log!("third");
+        clear_logger();
+    }
+}
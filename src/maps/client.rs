@@ -0,0 +1,143 @@
+use futures::stream::{self, StreamExt, TryStreamExt};
+
+use super::types::{MapLayer, StitchedMap, TileImage, TileIndex, TILE_SIZE};
+use crate::client::OpenWeatherClient;
+use crate::weather::{BoundingBox, Coord};
+
+const TILE_API_BASE_URL: &str = "https://tile.openweathermap.org/map";
+
+/// Upper bound on the number of tiles fetched for a single stitched map.
+const MAX_TILES_PER_MAP: u64 = 64;
+
+/// Highest zoom level served by the tile API.
+const MAX_ZOOM: u8 = 20;
+
+/// Number of tiles downloaded concurrently while stitching.
+const TILE_FETCH_CONCURRENCY: usize = 4;
+
+/// A client for fetching weather map tiles and stitching them into larger images.
+///
+/// # Fields
+/// - `client`: The shared client that sends the requests.
+pub struct MapClient {
+    client: OpenWeatherClient,
+}
+
+impl MapClient {
+    pub fn new(api_key: String) -> Self {
+        Self::with_client(OpenWeatherClient::new("standard".to_string(), api_key))
+    }
+
+    /// Creates a client that shares the connection pool and API key of `client`.
+    pub fn with_client(client: OpenWeatherClient) -> Self {
+        Self { client }
+    }
+
+    pub fn set_api_key(&mut self, api_key: String) {
+        self.client.set_api_key(api_key);
+    }
+
+    /// Fetches a single tile as raw PNG bytes.
+    pub async fn get_tile_png(&self, layer: MapLayer, tile: &TileIndex) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let url = format!("{}/{}/{}.png", TILE_API_BASE_URL, layer, tile);
+
+        Ok(self.client.send_request(&url, Vec::new()).await?.bytes().await?.to_vec())
+    }
+
+    /// Fetches a single tile and decodes it into an RGBA buffer.
+    pub async fn get_tile(&self, layer: MapLayer, tile: &TileIndex) -> Result<TileImage, Box<dyn std::error::Error>> {
+        let png = self.get_tile_png(layer, tile).await?;
+        Ok(TileImage::from_png(&png)?)
+    }
+
+    /// Fetches every tile covering `bbox` at `zoom` and stitches them into one image.
+    ///
+    /// At most 64 tiles are fetched; use a lower zoom level for larger areas. The box must not
+    /// cross the antimeridian (`lon_left <= lon_right`).
+    pub async fn get_map(&self, layer: MapLayer, bbox: &BoundingBox, zoom: u8) -> Result<StitchedMap, Box<dyn std::error::Error>> {
+        let (north_west, south_east) = tile_range(bbox, zoom)?;
+        let columns = south_east.x - north_west.x + 1;
+        let rows = south_east.y - north_west.y + 1;
+
+        let tiles: Vec<TileIndex> = (north_west.y..=south_east.y)
+            .flat_map(|y| (north_west.x..=south_east.x).map(move |x| TileIndex { zoom, x, y }))
+            .collect();
+
+        let images: Vec<(TileIndex, TileImage)> = stream::iter(tiles)
+            .map(|tile| async move { self.get_tile(layer, &tile).await.map(|image| (tile, image)) })
+            .buffer_unordered(TILE_FETCH_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let mut image = TileImage::new(columns * TILE_SIZE, rows * TILE_SIZE);
+        for (tile, tile_image) in &images {
+            image.draw(tile_image, (tile.x - north_west.x) * TILE_SIZE, (tile.y - north_west.y) * TILE_SIZE);
+        }
+
+        Ok(StitchedMap {
+            layer,
+            origin: north_west,
+            image,
+        })
+    }
+}
+
+/// Returns the north-west and south-east tiles covering `bbox`, rejecting zoom levels the API
+/// does not serve and boxes that need more than [`MAX_TILES_PER_MAP`] tiles.
+fn tile_range(bbox: &BoundingBox, zoom: u8) -> Result<(TileIndex, TileIndex), String> {
+    if zoom > MAX_ZOOM {
+        return Err(format!("Zoom level must be between 0 and {}", MAX_ZOOM));
+    }
+    if bbox.lon_left > bbox.lon_right || bbox.lat_bottom > bbox.lat_top {
+        return Err("Bounding box must have lon_left <= lon_right and lat_bottom <= lat_top".to_string());
+    }
+
+    let north_west = TileIndex::from_coord(&Coord::new(bbox.lon_left, bbox.lat_top)?, zoom)?;
+    let south_east = TileIndex::from_coord(&Coord::new(bbox.lon_right, bbox.lat_bottom)?, zoom)?;
+
+    let columns = u64::from(south_east.x - north_west.x) + 1;
+    let rows = u64::from(south_east.y - north_west.y) + 1;
+    match columns.checked_mul(rows) {
+        Some(tiles) if tiles <= MAX_TILES_PER_MAP => Ok((north_west, south_east)),
+        tiles => Err(format!(
+            "Bounding box covers {} tiles at zoom {}, the maximum is {}",
+            tiles.map_or_else(|| "too many".to_string(), |tiles| tiles.to_string()),
+            zoom,
+            MAX_TILES_PER_MAP
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn world() -> BoundingBox {
+        BoundingBox::new(-180.0, -85.0, 180.0, 85.0).unwrap()
+    }
+
+    #[test]
+    fn rejects_world_at_max_zoom_without_overflow() {
+        assert!(tile_range(&world(), 20).unwrap_err().contains("tiles at zoom 20"));
+    }
+
+    #[test]
+    fn rejects_zoom_above_max() {
+        assert!(tile_range(&world(), 21).is_err());
+    }
+
+    #[test]
+    fn accepts_small_boxes() {
+        let (north_west, south_east) = tile_range(&world(), 2).unwrap();
+        assert_eq!((north_west.x, north_west.y, south_east.x, south_east.y), (0, 0, 3, 3));
+
+        let london = BoundingBox::new(-0.2, 51.45, 0.0, 51.55).unwrap();
+        assert!(tile_range(&london, 10).is_ok());
+    }
+
+    #[test]
+    fn rejects_inverted_boxes() {
+        let inverted = BoundingBox { lon_left: 170.0, lat_bottom: -10.0, lon_right: -170.0, lat_top: 10.0 };
+        assert!(tile_range(&inverted, 3).is_err());
+    }
+}
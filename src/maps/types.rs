@@ -0,0 +1,366 @@
+use std::f64::consts::PI;
+use std::fmt;
+use std::io::Cursor;
+
+use crate::weather::Coord;
+
+/// Width and height of a single map tile in pixels.
+pub const TILE_SIZE: u32 = 256;
+
+/// Highest latitude representable in the Web Mercator projection used by map tiles.
+const MAX_MERCATOR_LAT: f64 = 85.051_128_78;
+
+// region: MapLayer
+
+/// Represents the weather map layers served by the tile API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapLayer {
+    /// Cloud coverage (`clouds_new`)
+    Clouds,
+    /// Precipitation intensity (`precipitation_new`)
+    Precipitation,
+    /// Sea level pressure (`pressure_new`)
+    Pressure,
+    /// Wind speed (`wind_new`)
+    Wind,
+    /// Temperature (`temp_new`)
+    Temperature,
+}
+
+impl MapLayer {
+    /// Returns the layer name used in tile URLs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MapLayer::Clouds => "clouds_new",
+            MapLayer::Precipitation => "precipitation_new",
+            MapLayer::Pressure => "pressure_new",
+            MapLayer::Wind => "wind_new",
+            MapLayer::Temperature => "temp_new",
+        }
+    }
+}
+
+impl fmt::Display for MapLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+// endregion
+
+// region: TileIndex
+
+/// Identifies a single tile in the Web Mercator ("slippy map") tile grid.
+///
+/// At zoom level `z` the world is covered by `2^z` × `2^z` tiles; `x` grows eastwards
+/// from the antimeridian and `y` grows southwards from the northern edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TileIndex {
+    /// Zoom level (0 to 20)
+    pub zoom: u8,
+    /// Column, from 0 to 2^zoom - 1
+    pub x: u32,
+    /// Row, from 0 to 2^zoom - 1
+    pub y: u32,
+}
+
+impl TileIndex {
+    pub fn new(zoom: u8, x: u32, y: u32) -> Result<Self, String> {
+        if zoom > 20 {
+            return Err("Zoom level must be between 0 and 20".to_string());
+        }
+        let tiles = 1u32 << zoom;
+        if x >= tiles || y >= tiles {
+            return Err(format!("Tile x and y must be between 0 and {} at zoom {}", tiles - 1, zoom));
+        }
+
+        Ok(TileIndex { zoom, x, y })
+    }
+
+    /// Returns the tile containing `coord` at the given zoom level.
+    ///
+    /// Latitudes beyond ±85.05° are clamped to the edge of the projection.
+    pub fn from_coord(coord: &Coord, zoom: u8) -> Result<Self, String> {
+        let (x, y) = world_pixel(coord, zoom)?;
+        let tiles = 1u32 << zoom;
+        let column = ((x / TILE_SIZE as f64).floor() as u32).min(tiles - 1);
+        let row = ((y / TILE_SIZE as f64).floor() as u32).min(tiles - 1);
+
+        TileIndex::new(zoom, column, row)
+    }
+
+    /// Returns the coordinate of the tile's north-west corner.
+    pub fn north_west(&self) -> Coord {
+        let tiles = (1u32 << self.zoom) as f64;
+        let lon = self.x as f64 / tiles * 360.0 - 180.0;
+        let lat = (PI * (1.0 - 2.0 * self.y as f64 / tiles)).sinh().atan().to_degrees();
+
+        Coord { lon, lat }
+    }
+}
+
+impl fmt::Display for TileIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.zoom, self.x, self.y)
+    }
+}
+
+/// Projects a coordinate onto the global pixel grid at `zoom` (256 pixels per tile).
+fn world_pixel(coord: &Coord, zoom: u8) -> Result<(f64, f64), String> {
+    if zoom > 20 {
+        return Err("Zoom level must be between 0 and 20".to_string());
+    }
+
+    let size = TILE_SIZE as f64 * (1u32 << zoom) as f64;
+    let lat = coord.lat.clamp(-MAX_MERCATOR_LAT, MAX_MERCATOR_LAT).to_radians();
+    let x = (coord.lon + 180.0) / 360.0 * size;
+    let y = (1.0 - (lat.tan() + 1.0 / lat.cos()).ln() / PI) / 2.0 * size;
+
+    Ok((x, y))
+}
+
+// endregion
+
+// region: TileImage
+
+/// An RGBA image buffer with 8 bits per channel, stored row by row.
+///
+/// The buffer always holds exactly `width * height * 4` bytes.
+#[derive(Debug, Clone)]
+pub struct TileImage {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl TileImage {
+    /// Creates a fully transparent image.
+    pub fn new(width: u32, height: u32) -> Self {
+        TileImage {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+        }
+    }
+
+    /// Wraps an RGBA buffer, rejecting buffers whose length is not `width * height * 4`.
+    pub fn from_rgba(width: u32, height: u32, pixels: Vec<u8>) -> Result<Self, String> {
+        let expected = (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|count| count.checked_mul(4))
+            .ok_or("Image is too large")?;
+        if pixels.len() != expected {
+            return Err(format!("Expected {} bytes for a {}x{} RGBA image, got {}", expected, width, height, pixels.len()));
+        }
+
+        Ok(TileImage { width, height, pixels })
+    }
+
+    /// Decodes a PNG file into an RGBA buffer, expanding palette, grayscale and 16-bit images.
+    pub fn from_png(bytes: &[u8]) -> Result<Self, String> {
+        let mut decoder = png::Decoder::new(Cursor::new(bytes));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info().map_err(|e| format!("Invalid PNG: {}", e))?;
+
+        let size = reader.output_buffer_size().ok_or("PNG image is too large")?;
+        let mut buffer = vec![0; size];
+        let info = reader.next_frame(&mut buffer).map_err(|e| format!("Invalid PNG: {}", e))?;
+        buffer.truncate(info.buffer_size());
+
+        let pixels = match info.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => buffer.chunks_exact(3).flat_map(|p| [p[0], p[1], p[2], 255]).collect(),
+            png::ColorType::GrayscaleAlpha => buffer.chunks_exact(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+            png::ColorType::Grayscale => buffer.iter().flat_map(|&p| [p, p, p, 255]).collect(),
+            png::ColorType::Indexed => return Err("Indexed PNG was not expanded".to_string()),
+        };
+
+        TileImage::from_rgba(info.width, info.height, pixels)
+    }
+
+    /// Width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Pixel data, 4 bytes (R, G, B, A) per pixel.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Consumes the image and returns its pixel data.
+    pub fn into_pixels(self) -> Vec<u8> {
+        self.pixels
+    }
+
+    /// RGBA value of the pixel at (`x`, `y`), or `None` outside the image.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let index = (y as usize * self.width as usize + x as usize) * 4;
+        Some([self.pixels[index], self.pixels[index + 1], self.pixels[index + 2], self.pixels[index + 3]])
+    }
+
+    /// Copies `source` into this image with its top-left corner at (`x`, `y`).
+    ///
+    /// Parts of `source` falling outside this image are ignored.
+    pub fn draw(&mut self, source: &TileImage, x: u32, y: u32) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        let (x, y) = (x as usize, y as usize);
+        let (width, source_width) = (self.width as usize, source.width as usize);
+        let columns = source_width.min(width - x) * 4;
+        let rows = (source.height as usize).min(self.height as usize - y);
+        for row in 0..rows {
+            let src = row * source_width * 4;
+            let dst = ((y + row) * width + x) * 4;
+            self.pixels[dst..dst + columns].copy_from_slice(&source.pixels[src..src + columns]);
+        }
+    }
+}
+
+// endregion
+
+// region: StitchedMap
+
+/// A map layer image assembled from adjacent tiles.
+///
+/// The image is aligned to the tile grid: its top-left pixel is the north-west corner of
+/// `origin`, so it can be overlaid directly on base maps using the same tile scheme.
+#[derive(Debug, Clone)]
+pub struct StitchedMap {
+    /// Layer the tiles were fetched from
+    pub layer: MapLayer,
+    /// Top-left tile of the image
+    pub origin: TileIndex,
+    /// Stitched image
+    pub image: TileImage,
+}
+
+impl StitchedMap {
+    /// Returns the pixel position of `coord` within the stitched image.
+    ///
+    /// The position may lie outside the image if `coord` is not covered by it.
+    pub fn pixel_position(&self, coord: &Coord) -> Result<(f64, f64), String> {
+        let (x, y) = world_pixel(coord, self.origin.zoom)?;
+        Ok((
+            x - (self.origin.x * TILE_SIZE) as f64,
+            y - (self.origin.y * TILE_SIZE) as f64,
+        ))
+    }
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coord(lon: f64, lat: f64) -> Coord {
+        Coord::new(lon, lat).unwrap()
+    }
+
+    fn solid(width: u32, height: u32, rgba: [u8; 4]) -> TileImage {
+        TileImage::from_rgba(width, height, rgba.repeat(width as usize * height as usize)).unwrap()
+    }
+
+    fn encode_png(width: u32, height: u32, color: png::ColorType, data: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, width, height);
+        encoder.set_color(color);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header().unwrap().write_image_data(data).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn tiles_of_known_places() {
+        assert_eq!(TileIndex::from_coord(&coord(-0.1278, 51.5074), 10).unwrap(), TileIndex::new(10, 511, 340).unwrap());
+        assert_eq!(TileIndex::from_coord(&coord(13.405, 52.52), 10).unwrap(), TileIndex::new(10, 550, 335).unwrap());
+        assert_eq!(TileIndex::from_coord(&coord(151.2093, -33.8688), 12).unwrap(), TileIndex::new(12, 3768, 2457).unwrap());
+        assert_eq!(TileIndex::from_coord(&coord(0.0, 0.0), 0).unwrap(), TileIndex::new(0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn tiles_at_the_edges_of_the_projection() {
+        assert_eq!(TileIndex::from_coord(&coord(180.0, -90.0), 3).unwrap(), TileIndex::new(3, 7, 7).unwrap());
+        assert_eq!(TileIndex::from_coord(&coord(-180.0, 90.0), 3).unwrap(), TileIndex::new(3, 0, 0).unwrap());
+        assert!(TileIndex::from_coord(&coord(0.0, 0.0), 21).is_err());
+        assert!(TileIndex::new(2, 4, 0).is_err());
+    }
+
+    #[test]
+    fn north_west_corners() {
+        let corner = TileIndex::new(1, 1, 1).unwrap().north_west();
+        assert!(corner.lon.abs() < 1e-9 && corner.lat.abs() < 1e-9);
+
+        let corner = TileIndex::new(0, 0, 0).unwrap().north_west();
+        assert_eq!(corner.lon, -180.0);
+        assert!((corner.lat - MAX_MERCATOR_LAT).abs() < 1e-6);
+
+        let tile = TileIndex::new(10, 550, 335).unwrap();
+        assert_eq!(TileIndex::from_coord(&tile.north_west(), 10).unwrap(), tile);
+    }
+
+    #[test]
+    fn from_rgba_checks_the_buffer_length() {
+        assert!(TileImage::from_rgba(2, 2, vec![0; 16]).is_ok());
+        assert!(TileImage::from_rgba(2, 2, vec![0; 15]).is_err());
+        assert!(TileImage::from_rgba(u32::MAX, u32::MAX, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn from_png_expands_to_rgba() {
+        let rgba = TileImage::from_png(&encode_png(2, 1, png::ColorType::Rgba, &[1, 2, 3, 4, 5, 6, 7, 8])).unwrap();
+        assert_eq!((rgba.width(), rgba.height()), (2, 1));
+        assert_eq!(rgba.pixels(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let rgb = TileImage::from_png(&encode_png(1, 2, png::ColorType::Rgb, &[10, 20, 30, 40, 50, 60])).unwrap();
+        assert_eq!(rgb.pixel(0, 1), Some([40, 50, 60, 255]));
+
+        let gray = TileImage::from_png(&encode_png(1, 1, png::ColorType::GrayscaleAlpha, &[90, 128])).unwrap();
+        assert_eq!(gray.pixel(0, 0), Some([90, 90, 90, 128]));
+
+        assert!(TileImage::from_png(b"not a png").is_err());
+    }
+
+    #[test]
+    fn draw_copies_and_clips() {
+        let mut canvas = TileImage::new(4, 3);
+        canvas.draw(&solid(2, 2, [255, 0, 0, 255]), 1, 0);
+        canvas.draw(&solid(3, 3, [0, 0, 255, 255]), 3, 2);
+        canvas.draw(&solid(1, 1, [0, 255, 0, 255]), 4, 0);
+
+        assert_eq!(canvas.pixel(0, 0), Some([0, 0, 0, 0]));
+        assert_eq!(canvas.pixel(1, 0), Some([255, 0, 0, 255]));
+        assert_eq!(canvas.pixel(2, 1), Some([255, 0, 0, 255]));
+        assert_eq!(canvas.pixel(3, 1), Some([0, 0, 0, 0]));
+        assert_eq!(canvas.pixel(3, 2), Some([0, 0, 255, 255]));
+        assert_eq!(canvas.pixel(2, 2), Some([0, 0, 0, 0]));
+        assert_eq!(canvas.pixel(4, 0), None);
+        assert_eq!(canvas.pixels().len(), 4 * 3 * 4);
+    }
+
+    #[test]
+    fn pixel_position_is_relative_to_the_origin_tile() {
+        let origin = TileIndex::new(10, 550, 335).unwrap();
+        let map = StitchedMap { layer: MapLayer::Clouds, origin, image: TileImage::new(512, 512) };
+
+        let (x, y) = map.pixel_position(&origin.north_west()).unwrap();
+        assert!(x.abs() < 1e-6 && y.abs() < 1e-6);
+
+        let (x, y) = map.pixel_position(&TileIndex::new(10, 551, 336).unwrap().north_west()).unwrap();
+        assert!((x - 256.0).abs() < 1e-6 && (y - 256.0).abs() < 1e-6);
+
+        let (x, y) = map.pixel_position(&coord(13.405, 52.52)).unwrap();
+        assert!((0.0..256.0).contains(&x) && (0.0..256.0).contains(&y));
+    }
+}
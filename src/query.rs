@@ -0,0 +1,57 @@
+use crate::geo::Coord;
+use crate::location::{Location, PostalCode};
+
+/// Represents the place a weather request is made for.
+///
+/// The current weather and forecast endpoints accept any of these forms; geographic
+/// coordinates are the most precise, the other forms are resolved by the API.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WeatherQuery {
+    /// Geographic coordinates
+    Coord(Coord),
+    /// City name, optionally followed by state and country codes (e.g. `London,GB`)
+    CityName(String),
+    /// Postal code and its country
+    Zip(PostalCode),
+    /// OpenWeatherMap city ID
+    CityId(i64),
+}
+
+impl WeatherQuery {
+    /// Query parameters identifying the place.
+    pub(crate) fn to_query(&self) -> Vec<(&'static str, String)> {
+        match self {
+            WeatherQuery::Coord(coord) => vec![
+                ("lat", coord.lat.to_string()),
+                ("lon", coord.lon.to_string()),
+            ],
+            WeatherQuery::CityName(name) => vec![("q", name.clone())],
+            WeatherQuery::Zip(postal_code) => vec![("zip", format!("{},{}", postal_code.code(), postal_code.country()))],
+            WeatherQuery::CityId(id) => vec![("id", id.to_string())],
+        }
+    }
+}
+
+impl From<Coord> for WeatherQuery {
+    fn from(coord: Coord) -> Self {
+        WeatherQuery::Coord(coord)
+    }
+}
+
+impl From<&Location> for WeatherQuery {
+    fn from(location: &Location) -> Self {
+        WeatherQuery::Coord(location.coord.clone())
+    }
+}
+
+impl From<Location> for WeatherQuery {
+    fn from(location: Location) -> Self {
+        WeatherQuery::Coord(location.coord)
+    }
+}
+
+impl From<PostalCode> for WeatherQuery {
+    fn from(postal_code: PostalCode) -> Self {
+        WeatherQuery::Zip(postal_code)
+    }
+}
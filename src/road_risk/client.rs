@@ -0,0 +1,47 @@
+use reqwest::Method;
+
+use super::types::{RoadRiskPoint, Track, TrackPoint};
+use crate::client::OpenWeatherClient;
+
+const ROAD_RISK_API_BASE_URL: &str = "https://api.openweathermap.org/data/2.5/roadrisk";
+
+/// A client for the Road Risk API, which reports weather, road surface state and
+/// alerts along a driving route.
+///
+/// # Fields
+/// - `client`: The shared client that sends the requests.
+pub struct RoadRiskClient {
+    client: OpenWeatherClient,
+}
+
+impl RoadRiskClient {
+    pub fn new(api_key: String) -> Self {
+        Self::with_client(OpenWeatherClient::new("standard".to_string(), api_key))
+    }
+
+    /// Creates a client that shares the connection pool and API key of `client`.
+    pub fn with_client(client: OpenWeatherClient) -> Self {
+        Self { client }
+    }
+
+    pub fn set_api_key(&mut self, api_key: String) {
+        self.client.set_api_key(api_key);
+    }
+
+    /// Fetches the conditions for every point of `track`.
+    ///
+    /// Points must be in travel order, i.e. sorted by time.
+    pub async fn get_road_risk(&self, track: Vec<TrackPoint>) -> Result<Vec<RoadRiskPoint>, Box<dyn std::error::Error>> {
+        if track.is_empty() {
+            return Err("Track must contain at least one point".into());
+        }
+        if track.windows(2).any(|pair| pair[0].dt > pair[1].dt) {
+            return Err("Track points must be ordered by time".into());
+        }
+
+        let request = self.client
+            .request(Method::POST, ROAD_RISK_API_BASE_URL)
+            .json(&Track { track });
+        Ok(self.client.send(request).await?.json().await?)
+    }
+}
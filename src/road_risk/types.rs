@@ -0,0 +1,299 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::scale::numeric_scale;
+use crate::weather::Coord;
+
+// region: TrackPoint
+
+/// Represents one point of a route: where the vehicle is expected to be, and when.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "lat": 42.4421,
+///     "lon": -82.1993,
+///     "dt": 1752449935
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrackPoint {
+    /// Coordinates of the point (`lat`/`lon` at the top level of the JSON object)
+    #[serde(flatten)]
+    pub coord: Coord,
+    /// Expected time at this point, unix, UTC
+    pub dt: i64,
+}
+
+impl TrackPoint {
+    pub fn new(coord: &Coord, dt: i64) -> Self {
+        TrackPoint { coord: coord.clone(), dt }
+    }
+}
+
+/// Represents the request body of the Road Risk API.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Track {
+    /// Route points in travel order
+    pub track: Vec<TrackPoint>,
+}
+
+// endregion
+
+// region: RoadState
+
+/// Represents the condition of the road surface.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum RoadState {
+    NoReport,
+    Dry,
+    Moist,
+    MoistChemicallyTreated,
+    Wet,
+    WetChemicallyTreated,
+    Ice,
+    Frost,
+    Snow,
+    SnowIceWatch,
+    SnowIceWarning,
+    WetAboveFreezing,
+    WetBelowFreezing,
+    Absorption,
+    AbsorptionAtDewpoint,
+    Dew,
+    BlackIceWarning,
+    Other,
+    Slush,
+    /// State code not known to this library
+    Unknown(u8),
+}
+
+impl From<u8> for RoadState {
+    fn from(code: u8) -> Self {
+        match code {
+            0 => RoadState::NoReport,
+            1 => RoadState::Dry,
+            2 => RoadState::Moist,
+            3 => RoadState::MoistChemicallyTreated,
+            4 => RoadState::Wet,
+            5 => RoadState::WetChemicallyTreated,
+            6 => RoadState::Ice,
+            7 => RoadState::Frost,
+            8 => RoadState::Snow,
+            9 => RoadState::SnowIceWatch,
+            10 => RoadState::SnowIceWarning,
+            11 => RoadState::WetAboveFreezing,
+            12 => RoadState::WetBelowFreezing,
+            13 => RoadState::Absorption,
+            14 => RoadState::AbsorptionAtDewpoint,
+            15 => RoadState::Dew,
+            16 => RoadState::BlackIceWarning,
+            17 => RoadState::Other,
+            18 => RoadState::Slush,
+            other => RoadState::Unknown(other),
+        }
+    }
+}
+
+impl From<RoadState> for u8 {
+    fn from(state: RoadState) -> Self {
+        match state {
+            RoadState::NoReport => 0,
+            RoadState::Dry => 1,
+            RoadState::Moist => 2,
+            RoadState::MoistChemicallyTreated => 3,
+            RoadState::Wet => 4,
+            RoadState::WetChemicallyTreated => 5,
+            RoadState::Ice => 6,
+            RoadState::Frost => 7,
+            RoadState::Snow => 8,
+            RoadState::SnowIceWatch => 9,
+            RoadState::SnowIceWarning => 10,
+            RoadState::WetAboveFreezing => 11,
+            RoadState::WetBelowFreezing => 12,
+            RoadState::Absorption => 13,
+            RoadState::AbsorptionAtDewpoint => 14,
+            RoadState::Dew => 15,
+            RoadState::BlackIceWarning => 16,
+            RoadState::Other => 17,
+            RoadState::Slush => 18,
+            RoadState::Unknown(code) => code,
+        }
+    }
+}
+
+impl fmt::Display for RoadState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            RoadState::NoReport => "no report",
+            RoadState::Dry => "dry",
+            RoadState::Moist => "moist",
+            RoadState::MoistChemicallyTreated => "moist and chemically treated",
+            RoadState::Wet => "wet",
+            RoadState::WetChemicallyTreated => "wet and chemically treated",
+            RoadState::Ice => "ice",
+            RoadState::Frost => "frost",
+            RoadState::Snow => "snow",
+            RoadState::SnowIceWatch => "snow/ice watch",
+            RoadState::SnowIceWarning => "snow/ice warning",
+            RoadState::WetAboveFreezing => "wet above freezing",
+            RoadState::WetBelowFreezing => "wet below freezing",
+            RoadState::Absorption => "absorption",
+            RoadState::AbsorptionAtDewpoint => "absorption at dewpoint",
+            RoadState::Dew => "dew",
+            RoadState::BlackIceWarning => "black ice warning",
+            RoadState::Other => "other",
+            RoadState::Slush => "slush",
+            RoadState::Unknown(code) => return write!(f, "unknown ({})", code),
+        };
+        write!(f, "{}", description)
+    }
+}
+
+// endregion
+
+// region: AlertSeverity
+
+/// Represents the severity of a road weather alert, from least to most severe.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum AlertSeverity {
+    Minor,
+    Moderate,
+    Severe,
+    Extreme,
+    /// Severity level not known to this library
+    Unknown(u8),
+}
+
+numeric_scale!(AlertSeverity {
+    1 => Minor,
+    2 => Moderate,
+    3 => Severe,
+    4 => Extreme,
+});
+
+// endregion
+
+// region: RoadRiskPoint
+
+/// Represents the weather expected at a route point.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RoadWeather {
+    /// Air temperature, Kelvin
+    pub temp: Option<f64>,
+    /// Wind speed, m/s
+    pub wind_speed: Option<f64>,
+    /// Wind direction in degrees (meteorological)
+    pub wind_deg: Option<f64>,
+    /// Precipitation intensity, mm
+    pub precipitation_intensity: Option<f64>,
+    /// Dew point, Kelvin
+    pub dew_point: Option<f64>,
+}
+
+/// Represents the road surface condition at a route point.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RoadCondition {
+    /// Surface state
+    pub state: RoadState,
+    /// Surface temperature, Kelvin
+    pub temp: Option<f64>,
+}
+
+/// Represents a national weather alert affecting a route point.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RoadAlert {
+    /// Name of the alert source
+    pub sender_name: String,
+    /// Alert event name
+    pub event: String,
+    /// Alert severity
+    pub event_level: AlertSeverity,
+}
+
+/// Represents the weather, road state and alerts at one route point.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "dt": 1752449935,
+///     "coord": [42.4421, -82.1993],
+///     "weather": {
+///         "temp": 300.92,
+///         "wind_speed": 3.6,
+///         "wind_deg": 220,
+///         "precipitation_intensity": 0.0,
+///         "dew_point": 292.95
+///     },
+///     "road": {
+///         "state": 1,
+///         "temp": 309.65
+///     },
+///     "alerts": [
+///         {
+///             "sender_name": "Environment Canada",
+///             "event": "Heat warning",
+///             "event_level": 2
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RoadRiskPoint {
+    /// Time at this point, unix, UTC
+    pub dt: i64,
+    /// Coordinates of the point (`[lat, lon]` in the JSON)
+    #[serde(with = "crate::geo::types::lat_lon_array")]
+    pub coord: Coord,
+    /// Expected weather
+    pub weather: Option<RoadWeather>,
+    /// Expected road condition
+    pub road: Option<RoadCondition>,
+    /// Active alerts
+    #[serde(default)]
+    pub alerts: Vec<RoadAlert>,
+}
+
+impl RoadRiskPoint {
+    /// Returns the most severe alert at this point, if any.
+    pub fn max_severity(&self) -> Option<AlertSeverity> {
+        self.alerts.iter().map(|alert| alert.event_level).max()
+    }
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn severities_order_by_level() {
+        assert!(AlertSeverity::Minor < AlertSeverity::Extreme);
+        assert!(AlertSeverity::Unknown(0) < AlertSeverity::Minor);
+        assert!(AlertSeverity::Unknown(3) > AlertSeverity::Moderate);
+        assert!(AlertSeverity::Unknown(3) < AlertSeverity::Extreme);
+    }
+
+    #[test]
+    fn track_point_keeps_lat_lon_at_the_top_level() {
+        let point = TrackPoint::new(&Coord::new(-82.1993, 42.4421).unwrap(), 1752449935);
+        let json = serde_json::to_value(&point).unwrap();
+        assert_eq!(json, serde_json::json!({ "lon": -82.1993, "lat": 42.4421, "dt": 1752449935 }));
+
+        let parsed: TrackPoint = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.coord, point.coord);
+        assert!(serde_json::from_str::<TrackPoint>(r#"{"lat": 100, "lon": 0, "dt": 0}"#).is_err());
+    }
+
+    #[test]
+    fn risk_point_coord_is_a_lat_lon_array() {
+        let json = r#"{"dt": 1752449935, "coord": [42.4421, -82.1993], "weather": null, "road": null}"#;
+        let point: RoadRiskPoint = serde_json::from_str(json).unwrap();
+        assert_eq!(point.coord, Coord::new(-82.1993, 42.4421).unwrap());
+        assert!(point.alerts.is_empty());
+        assert_eq!(serde_json::to_value(&point).unwrap()["coord"], serde_json::json!([42.4421, -82.1993]));
+    }
+}
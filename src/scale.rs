@@ -0,0 +1,45 @@
+//! Shared implementation of the numeric rating scales the API returns (Air Quality Index,
+//! fire danger rating, road alert severity).
+
+/// Implements `From<u8>`, `From<Scale> for u8`, `Ord` and `PartialOrd` for an enum whose
+/// variants map to the API's numeric values, plus an `Unknown(u8)` variant for values this
+/// library does not know.
+///
+/// Values order by their number, so an unknown value ranks where its number falls. A known
+/// variant ranks before an `Unknown` carrying the same number.
+macro_rules! numeric_scale {
+    ($scale:ident { $($value:literal => $variant:ident),+ $(,)? }) => {
+        impl From<u8> for $scale {
+            fn from(value: u8) -> Self {
+                match value {
+                    $($value => $scale::$variant,)+
+                    other => $scale::Unknown(other),
+                }
+            }
+        }
+
+        impl From<$scale> for u8 {
+            fn from(scale: $scale) -> Self {
+                match scale {
+                    $($scale::$variant => $value,)+
+                    $scale::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl Ord for $scale {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                let rank = |value: &Self| (u8::from(*value), matches!(value, $scale::Unknown(_)));
+                rank(self).cmp(&rank(other))
+            }
+        }
+
+        impl PartialOrd for $scale {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+    };
+}
+
+pub(crate) use numeric_scale;
@@ -0,0 +1,77 @@
+use super::types::{DailySolarEnergy, SolarInterval, SolarIntervalData};
+use crate::calendar::{format_date, parse_date};
+use crate::client::OpenWeatherClient;
+use crate::query::WeatherQuery;
+use crate::weather::Coord;
+
+const SOLAR_API_BASE_URL: &str = "https://api.openweathermap.org/energy/1.0/solar/interval_data";
+
+/// Upper bound on the number of days fetched by a single range request.
+const MAX_RANGE_DAYS: i64 = 366;
+
+/// A client for the Solar Irradiance API.
+///
+/// # Fields
+/// - `client`: The shared client that sends the requests.
+/// - `coord`: Coordinates the irradiance is requested for.
+pub struct SolarClient {
+    client: OpenWeatherClient,
+    coord: Coord,
+}
+
+impl SolarClient {
+    pub fn new(coord: impl Into<Coord>, api_key: String) -> Self {
+        Self::with_client(coord, OpenWeatherClient::new("standard".to_string(), api_key))
+    }
+
+    /// Creates a client that shares the connection pool and API key of `client`.
+    pub fn with_client(coord: impl Into<Coord>, client: OpenWeatherClient) -> Self {
+        Self {
+            client,
+            coord: coord.into(),
+        }
+    }
+
+    pub fn set_coord(&mut self, coord: impl Into<Coord>) {
+        self.coord = coord.into();
+    }
+
+    pub fn get_coord(&self) -> Coord {
+        self.coord.clone()
+    }
+
+    /// Fetches the irradiance for one date (`YYYY-MM-DD`, local time at the coordinates).
+    pub async fn get_interval_data(&self, date: &str, interval: SolarInterval) -> Result<SolarIntervalData, Box<dyn std::error::Error>> {
+        parse_date(date)?;
+
+        let mut query = WeatherQuery::Coord(self.coord.clone()).to_query();
+        query.push(("date", date.to_string()));
+        query.push(("interval", interval.to_string()));
+
+        Ok(self.client.send_request(SOLAR_API_BASE_URL, query).await?.json().await?)
+    }
+
+    /// Fetches the irradiance for every date from `start` to `end` inclusive (`YYYY-MM-DD`).
+    pub async fn get_interval_data_range(&self, start: &str, end: &str, interval: SolarInterval) -> Result<Vec<SolarIntervalData>, Box<dyn std::error::Error>> {
+        let first = parse_date(start)?;
+        let last = parse_date(end)?;
+        if first > last {
+            return Err("Start date must not be after end date".into());
+        }
+        if last - first >= MAX_RANGE_DAYS {
+            return Err(format!("Date range must not exceed {} days", MAX_RANGE_DAYS).into());
+        }
+
+        let mut days = Vec::new();
+        for day in first..=last {
+            days.push(self.get_interval_data(&format_date(day), interval).await?);
+        }
+        Ok(days)
+    }
+
+    /// Fetches the irradiance for a date range and integrates it into daily energy, kWh/m².
+    pub async fn get_daily_energy(&self, start: &str, end: &str) -> Result<Vec<DailySolarEnergy>, Box<dyn std::error::Error>> {
+        let days = self.get_interval_data_range(start, end, SolarInterval::FifteenMinutes).await?;
+        Ok(days.iter().map(SolarIntervalData::daily_energy).collect())
+    }
+}
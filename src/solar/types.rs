@@ -0,0 +1,279 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::weather::Coord;
+
+// region: SolarInterval
+
+/// Represents the time resolution of solar irradiance data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolarInterval {
+    /// 15 minute intervals (`15m`)
+    FifteenMinutes,
+    /// One hour intervals (`1h`)
+    OneHour,
+}
+
+impl SolarInterval {
+    /// Returns the interval name used in requests.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SolarInterval::FifteenMinutes => "15m",
+            SolarInterval::OneHour => "1h",
+        }
+    }
+
+    /// Returns the interval length in hours.
+    pub fn hours(&self) -> f64 {
+        match self {
+            SolarInterval::FifteenMinutes => 0.25,
+            SolarInterval::OneHour => 1.0,
+        }
+    }
+}
+
+impl fmt::Display for SolarInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+// endregion
+
+// region: Irradiance
+
+/// Represents the solar irradiance components in W/m².
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "ghi": 812.4,
+///     "dni": 873.1,
+///     "dhi": 104.6
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Irradiance {
+    /// Global horizontal irradiance
+    pub ghi: f64,
+    /// Direct normal irradiance
+    pub dni: f64,
+    /// Diffuse horizontal irradiance
+    pub dhi: f64,
+}
+
+/// Represents irradiance under clear-sky (theoretical) and cloudy-sky (actual cloud cover) conditions.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SkyIrradiance {
+    /// Irradiance assuming a cloudless sky
+    pub clear_sky: Irradiance,
+    /// Irradiance taking cloud cover into account
+    pub cloudy_sky: Irradiance,
+}
+
+// endregion
+
+// region: SolarIntervalData
+
+/// Represents the irradiance during one interval of the day.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "start": "12:00",
+///     "end": "13:00",
+///     "avg_irradiance": {
+///         "clear_sky": { "ghi": 812.4, "dni": 873.1, "dhi": 104.6 },
+///         "cloudy_sky": { "ghi": 655.2, "dni": 590.3, "dhi": 182.9 }
+///     },
+///     "max_irradiance": {
+///         "clear_sky": { "ghi": 825.0, "dni": 880.2, "dhi": 106.1 },
+///         "cloudy_sky": { "ghi": 671.8, "dni": 602.7, "dhi": 188.4 }
+///     }
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SolarIntervalEntry {
+    /// Start of the interval, HH:MM local time
+    pub start: String,
+    /// End of the interval, HH:MM local time
+    pub end: String,
+    /// Average irradiance during the interval, W/m²
+    pub avg_irradiance: SkyIrradiance,
+    /// Maximum irradiance during the interval, W/m² (optional)
+    pub max_irradiance: Option<SkyIrradiance>,
+}
+
+/// Represents the solar irradiance for one day at one location.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "lat": 42.4421,
+///     "lon": -82.1993,
+///     "date": "2025-07-13",
+///     "interval": "1h",
+///     "tz": "-04:00",
+///     "sunrise": "2025-07-13T06:03:28",
+///     "sunset": "2025-07-13T21:05:16",
+///     "intervals": []
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SolarIntervalData {
+    /// Coordinates of the location (`lat`/`lon` at the top level of the JSON object)
+    #[serde(flatten)]
+    pub coord: Coord,
+    /// Date, YYYY-MM-DD
+    pub date: String,
+    /// Interval length (`15m` or `1h`)
+    pub interval: String,
+    /// Timezone in the ±XX:XX format
+    pub tz: String,
+    /// Sunrise, local time, ISO format
+    pub sunrise: Option<String>,
+    /// Sunset, local time, ISO format
+    pub sunset: Option<String>,
+    /// Irradiance for each interval of the day
+    pub intervals: Vec<SolarIntervalEntry>,
+}
+
+impl SolarIntervalData {
+    /// Integrates the average global horizontal irradiance over the day into kWh/m².
+    pub fn daily_energy(&self) -> DailySolarEnergy {
+        let (clear_sky, cloudy_sky) = self.intervals.iter().fold((0.0, 0.0), |(clear, cloudy), entry| {
+            let hours = interval_hours(&entry.start, &entry.end).unwrap_or_else(|| self.default_interval_hours());
+            (
+                clear + entry.avg_irradiance.clear_sky.ghi * hours,
+                cloudy + entry.avg_irradiance.cloudy_sky.ghi * hours,
+            )
+        });
+
+        DailySolarEnergy {
+            date: self.date.clone(),
+            clear_sky: clear_sky / 1000.0,
+            cloudy_sky: cloudy_sky / 1000.0,
+        }
+    }
+
+    fn default_interval_hours(&self) -> f64 {
+        match self.interval.as_str() {
+            "15m" => SolarInterval::FifteenMinutes.hours(),
+            _ => SolarInterval::OneHour.hours(),
+        }
+    }
+}
+
+/// Length in hours between two `HH:MM` times, or `None` if either is not a valid time or the
+/// interval runs backwards. An end of `00:00` or `24:00` means the following midnight.
+fn interval_hours(start: &str, end: &str) -> Option<f64> {
+    let minutes = |value: &str| -> Option<i32> {
+        let (hours, minutes) = value.split_once(':')?;
+        let (hours, minutes) = (hours.parse::<i32>().ok()?, minutes.parse::<i32>().ok()?);
+        if !(0..60).contains(&minutes) || !(0..24).contains(&hours) && (hours, minutes) != (24, 0) {
+            return None;
+        }
+        Some(hours * 60 + minutes)
+    };
+
+    let start = minutes(start)?;
+    let end = match minutes(end)? {
+        0 if start > 0 => 24 * 60,
+        end => end,
+    };
+    if end < start {
+        return None;
+    }
+    Some((end - start) as f64 / 60.0)
+}
+
+// endregion
+
+// region: DailySolarEnergy
+
+/// Represents the solar energy received by a horizontal surface during one day.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DailySolarEnergy {
+    /// Date, YYYY-MM-DD
+    pub date: String,
+    /// Energy assuming a cloudless sky, kWh/m²
+    pub clear_sky: f64,
+    /// Energy taking cloud cover into account, kWh/m²
+    pub cloudy_sky: f64,
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(start: &str, end: &str, clear: f64, cloudy: f64) -> serde_json::Value {
+        let irradiance = |ghi: f64| serde_json::json!({ "ghi": ghi, "dni": 0.0, "dhi": 0.0 });
+        serde_json::json!({
+            "start": start,
+            "end": end,
+            "avg_irradiance": { "clear_sky": irradiance(clear), "cloudy_sky": irradiance(cloudy) },
+            "max_irradiance": null
+        })
+    }
+
+    fn data(interval: &str, intervals: Vec<serde_json::Value>) -> SolarIntervalData {
+        serde_json::from_value(serde_json::json!({
+            "lat": 42.4421,
+            "lon": -82.1993,
+            "date": "2025-07-13",
+            "interval": interval,
+            "tz": "-04:00",
+            "sunrise": null,
+            "sunset": null,
+            "intervals": intervals
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn interval_hours_measures_forward_intervals() {
+        assert_eq!(interval_hours("12:00", "13:00"), Some(1.0));
+        assert_eq!(interval_hours("12:00", "12:15"), Some(0.25));
+        assert_eq!(interval_hours("00:00", "01:00"), Some(1.0));
+        assert_eq!(interval_hours("12:00", "12:00"), Some(0.0));
+    }
+
+    #[test]
+    fn interval_hours_treats_a_midnight_end_as_the_next_day() {
+        assert_eq!(interval_hours("23:00", "00:00"), Some(1.0));
+        assert_eq!(interval_hours("23:45", "24:00"), Some(0.25));
+        assert_eq!(interval_hours("00:00", "00:00"), Some(0.0));
+    }
+
+    #[test]
+    fn interval_hours_rejects_invalid_times() {
+        assert_eq!(interval_hours("99:99", "00:00"), None);
+        assert_eq!(interval_hours("12:60", "13:00"), None);
+        assert_eq!(interval_hours("24:01", "24:00"), None);
+        assert_eq!(interval_hours("noon", "13:00"), None);
+        assert_eq!(interval_hours("13:00", "12:00"), None);
+    }
+
+    #[test]
+    fn daily_energy_integrates_ghi_per_interval() {
+        let energy = data("1h", vec![
+            entry("12:00", "13:00", 800.0, 600.0),
+            entry("23:00", "00:00", 100.0, 50.0),
+            entry("13:00", "13:00", 1000.0, 1000.0),
+        ])
+        .daily_energy();
+
+        assert_eq!(energy.date, "2025-07-13");
+        assert!((energy.clear_sky - 0.9).abs() < 1e-9);
+        assert!((energy.cloudy_sky - 0.65).abs() < 1e-9);
+    }
+
+    #[test]
+    fn daily_energy_falls_back_to_the_interval_length() {
+        let energy = data("15m", vec![entry("bad", "12:15", 400.0, 200.0)]).daily_energy();
+        assert!((energy.clear_sky - 0.1).abs() < 1e-9);
+        assert!((energy.cloudy_sky - 0.05).abs() < 1e-9);
+    }
+}
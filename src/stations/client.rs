@@ -0,0 +1,89 @@
+use reqwest::Method;
+
+use super::types::{AggregatedMeasurement, Measurement, MeasurementAggregation, NewStation, Station};
+use crate::client::{url_with_segment, OpenWeatherClient};
+
+const STATIONS_API_BASE_URL: &str = "https://api.openweathermap.org/data/3.0/stations";
+const MEASUREMENTS_API_BASE_URL: &str = "https://api.openweathermap.org/data/3.0/measurements";
+
+/// A client for publishing private weather stations and their measurements.
+///
+/// # Fields
+/// - `client`: The shared client that sends the requests, holding the API key of the account
+///   owning the stations.
+pub struct StationsClient {
+    client: OpenWeatherClient,
+}
+
+impl StationsClient {
+    pub fn new(api_key: String) -> Self {
+        Self::with_client(OpenWeatherClient::new("standard".to_string(), api_key))
+    }
+
+    /// Creates a client that shares the connection pool and API key of `client`.
+    pub fn with_client(client: OpenWeatherClient) -> Self {
+        Self { client }
+    }
+
+    pub fn set_api_key(&mut self, api_key: String) {
+        self.client.set_api_key(api_key);
+    }
+
+    /// Registers a new station and returns it with its assigned ID.
+    pub async fn create_station(&self, station: &NewStation) -> Result<Station, Box<dyn std::error::Error>> {
+        let request = self.client.request(Method::POST, STATIONS_API_BASE_URL).json(station);
+        Ok(self.client.send(request).await?.json().await?)
+    }
+
+    /// Lists every station owned by the account.
+    pub async fn get_stations(&self) -> Result<Vec<Station>, Box<dyn std::error::Error>> {
+        let request = self.client.request(Method::GET, STATIONS_API_BASE_URL);
+        Ok(self.client.send(request).await?.json().await?)
+    }
+
+    pub async fn get_station(&self, id: &str) -> Result<Station, Box<dyn std::error::Error>> {
+        let request = self.client.request(Method::GET, &station_url(id)?);
+        Ok(self.client.send(request).await?.json().await?)
+    }
+
+    pub async fn update_station(&self, id: &str, station: &NewStation) -> Result<Station, Box<dyn std::error::Error>> {
+        let request = self.client.request(Method::PUT, &station_url(id)?).json(station);
+        Ok(self.client.send(request).await?.json().await?)
+    }
+
+    pub async fn delete_station(&self, id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let request = self.client.request(Method::DELETE, &station_url(id)?);
+        self.client.send(request).await?;
+        Ok(())
+    }
+
+    /// Pushes raw measurements, possibly for several stations at once.
+    pub async fn post_measurements(&self, measurements: &[Measurement]) -> Result<(), Box<dyn std::error::Error>> {
+        let request = self.client.request(Method::POST, MEASUREMENTS_API_BASE_URL).json(measurements);
+        self.client.send(request).await?;
+        Ok(())
+    }
+
+    /// Fetches measurements of a station aggregated per `aggregation` period between
+    /// `from` and `to` (unix, UTC), returning at most `limit` periods.
+    pub async fn get_measurements(&self, station_id: &str, aggregation: MeasurementAggregation, from: i64, to: i64, limit: u32) -> Result<Vec<AggregatedMeasurement>, Box<dyn std::error::Error>> {
+        if from > to {
+            return Err("Measurement range start must not be after end".into());
+        }
+
+        let request = self.client
+            .request(Method::GET, MEASUREMENTS_API_BASE_URL)
+            .query(&[
+                ("station_id", station_id.to_string()),
+                ("type", aggregation.to_string()),
+                ("limit", limit.to_string()),
+                ("from", from.to_string()),
+                ("to", to.to_string())
+            ]);
+        Ok(self.client.send(request).await?.json().await?)
+    }
+}
+
+fn station_url(id: &str) -> Result<String, String> {
+    url_with_segment(STATIONS_API_BASE_URL, id)
+}
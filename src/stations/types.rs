@@ -0,0 +1,324 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::weather::Coord;
+
+// region: Station
+
+/// Represents the parameters used to register or update a weather station.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "external_id": "CHATHAM_TEST001",
+///     "name": "Chatham Rooftop Station",
+///     "latitude": 42.4421,
+///     "longitude": -82.1993,
+///     "altitude": 180
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NewStation {
+    /// Station identifier chosen by the owner
+    pub external_id: String,
+    /// Station name
+    pub name: String,
+    /// Coordinates of the station (`latitude`/`longitude` at the top level of the JSON object)
+    #[serde(flatten, with = "crate::geo::types::long_names")]
+    pub coord: Coord,
+    /// Altitude in meters
+    pub altitude: f64,
+}
+
+impl NewStation {
+    pub fn new(external_id: String, name: String, coord: &Coord, altitude: f64) -> Result<Self, String> {
+        if external_id.trim().is_empty() {
+            return Err("Station external ID must not be empty".to_string());
+        }
+        if name.trim().is_empty() {
+            return Err("Station name must not be empty".to_string());
+        }
+        Ok(NewStation { external_id, name, coord: coord.clone(), altitude })
+    }
+}
+
+/// Represents a weather station registered with the Weather Stations API.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "id": "5ed21a12cca8ce0001f1aef1",
+///     "created_at": "2025-07-13T08:47:14.449Z",
+///     "updated_at": "2025-07-13T08:47:14.449Z",
+///     "external_id": "CHATHAM_TEST001",
+///     "name": "Chatham Rooftop Station",
+///     "longitude": -82.1993,
+///     "latitude": 42.4421,
+///     "altitude": 180,
+///     "rank": 10
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Station {
+    /// Station ID assigned by OpenWeatherMap
+    #[serde(alias = "ID")]
+    pub id: String,
+    /// Creation time, ISO format, UTC
+    pub created_at: Option<String>,
+    /// Last update time, ISO format, UTC
+    pub updated_at: Option<String>,
+    /// Station identifier chosen by the owner
+    pub external_id: String,
+    /// Station name
+    pub name: String,
+    /// Coordinates of the station (`latitude`/`longitude` at the top level of the JSON object)
+    #[serde(flatten, with = "crate::geo::types::long_names")]
+    pub coord: Coord,
+    /// Altitude in meters
+    pub altitude: Option<f64>,
+    /// Station rank (internal parameter)
+    pub rank: Option<i32>,
+}
+
+// endregion
+
+// region: Measurement
+
+/// Represents a raw measurement pushed by a station.
+///
+/// Only `station_id` and `dt` are required; unset fields are omitted from the request.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "station_id": "5ed21a12cca8ce0001f1aef1",
+///     "dt": 1752449935,
+///     "temperature": 27.7,
+///     "wind_speed": 3.6,
+///     "wind_deg": 220,
+///     "pressure": 1014,
+///     "humidity": 62,
+///     "rain_1h": 0.2
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Measurement {
+    /// Station ID assigned by OpenWeatherMap
+    pub station_id: String,
+    /// Time of measurement, unix, UTC
+    pub dt: i64,
+    /// Air temperature, °C
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+    /// Wind speed, m/s
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wind_speed: Option<f64>,
+    /// Wind gust, m/s
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wind_gust: Option<f64>,
+    /// Wind direction in degrees (meteorological)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wind_deg: Option<i32>,
+    /// Atmospheric pressure, hPa
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pressure: Option<f64>,
+    /// Humidity, %
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub humidity: Option<f64>,
+    /// Rain in the last hour, mm
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rain_1h: Option<f64>,
+    /// Rain in the last 6 hours, mm
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rain_6h: Option<f64>,
+    /// Rain in the last 24 hours, mm
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rain_24h: Option<f64>,
+    /// Snow in the last hour, mm
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snow_1h: Option<f64>,
+    /// Snow in the last 6 hours, mm
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snow_6h: Option<f64>,
+    /// Snow in the last 24 hours, mm
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snow_24h: Option<f64>,
+    /// Visibility, km
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility_distance: Option<f64>,
+    /// Dew point, °C
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dew_point: Option<f64>,
+}
+
+impl Measurement {
+    pub fn new(station_id: String, dt: i64) -> Result<Self, String> {
+        if station_id.trim().is_empty() {
+            return Err("Measurement station ID must not be empty".to_string());
+        }
+
+        Ok(Measurement { station_id, dt, ..Default::default() })
+    }
+}
+
+// endregion
+
+// region: AggregatedMeasurement
+
+/// Represents the aggregation period of fetched measurements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeasurementAggregation {
+    /// One minute (`m`)
+    Minute,
+    /// One hour (`h`)
+    Hour,
+    /// One day (`d`)
+    Day,
+}
+
+impl MeasurementAggregation {
+    /// Returns the aggregation name used in requests.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MeasurementAggregation::Minute => "m",
+            MeasurementAggregation::Hour => "h",
+            MeasurementAggregation::Day => "d",
+        }
+    }
+}
+
+impl fmt::Display for MeasurementAggregation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Represents an aggregated value with its minimum, maximum and average.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AggregatedValue {
+    /// Minimum value in the period (optional)
+    pub min: Option<f64>,
+    /// Maximum value in the period (optional)
+    pub max: Option<f64>,
+    /// Average value in the period
+    pub average: Option<f64>,
+    /// Number of measurements in the period
+    pub weight: Option<i32>,
+}
+
+/// Represents the aggregated wind of a period.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AggregatedWind {
+    /// Wind direction in degrees (meteorological)
+    pub deg: Option<f64>,
+    /// Wind speed, m/s
+    pub speed: Option<f64>,
+}
+
+/// Represents the aggregated precipitation of a period.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AggregatedPrecipitation {
+    /// Rain, mm
+    pub rain: Option<f64>,
+    /// Snow, mm
+    pub snow: Option<f64>,
+}
+
+/// Represents station measurements aggregated over one period.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "type": "h",
+///     "date": 1752447600,
+///     "station_id": "5ed21a12cca8ce0001f1aef1",
+///     "temp": { "max": 28.1, "min": 27.2, "average": 27.7, "weight": 12 },
+///     "humidity": { "average": 62, "weight": 12 },
+///     "wind": { "deg": 220, "speed": 3.6 },
+///     "pressure": { "min": 1013, "max": 1014, "average": 1013.6, "weight": 12 },
+///     "precipitation": { "rain": 0.2 }
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AggregatedMeasurement {
+    /// Aggregation period (`m`, `h` or `d`)
+    #[serde(rename = "type")]
+    pub aggregation: String,
+    /// Start of the period, unix, UTC
+    pub date: i64,
+    /// Station ID assigned by OpenWeatherMap
+    pub station_id: String,
+    /// Air temperature, °C
+    pub temp: Option<AggregatedValue>,
+    /// Humidity, %
+    pub humidity: Option<AggregatedValue>,
+    /// Wind
+    pub wind: Option<AggregatedWind>,
+    /// Atmospheric pressure, hPa
+    pub pressure: Option<AggregatedValue>,
+    /// Precipitation
+    pub precipitation: Option<AggregatedPrecipitation>,
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_station_json_shape() {
+        let coord = Coord::new(-82.1993, 42.4421).unwrap();
+        let station = NewStation::new("CHATHAM_TEST001".to_string(), "Chatham Rooftop Station".to_string(), &coord, 180.0).unwrap();
+        assert_eq!(
+            serde_json::to_value(&station).unwrap(),
+            serde_json::json!({
+                "external_id": "CHATHAM_TEST001",
+                "name": "Chatham Rooftop Station",
+                "latitude": 42.4421,
+                "longitude": -82.1993,
+                "altitude": 180.0
+            })
+        );
+
+        assert!(NewStation::new(" ".to_string(), "x".to_string(), &coord, 0.0).is_err());
+        assert!(NewStation::new("x".to_string(), "".to_string(), &coord, 0.0).is_err());
+    }
+
+    #[test]
+    fn station_reads_latitude_and_longitude() {
+        let json = r#"{
+            "ID": "5ed21a12cca8ce0001f1aef1",
+            "external_id": "CHATHAM_TEST001",
+            "name": "Chatham Rooftop Station",
+            "longitude": -82.1993,
+            "latitude": 42.4421,
+            "altitude": 180,
+            "rank": 10
+        }"#;
+        let station: Station = serde_json::from_str(json).unwrap();
+        assert_eq!(station.id, "5ed21a12cca8ce0001f1aef1");
+        assert_eq!(station.coord, Coord::new(-82.1993, 42.4421).unwrap());
+        assert_eq!(station.created_at, None);
+    }
+
+    #[test]
+    fn measurement_skips_unset_fields() {
+        let measurement = Measurement {
+            station_id: "5ed21a12cca8ce0001f1aef1".to_string(),
+            dt: 1752449935,
+            temperature: Some(27.7),
+            rain_1h: Some(0.2),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&measurement).unwrap(),
+            serde_json::json!({
+                "station_id": "5ed21a12cca8ce0001f1aef1",
+                "dt": 1752449935,
+                "temperature": 27.7,
+                "rain_1h": 0.2
+            })
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use super::types::{AccumulatedPrecipitation, AccumulatedTemperature, AggregatedResponse, AggregatedWeather};
+use crate::calendar;
+use crate::client::OpenWeatherClient;
+use crate::query::WeatherQuery;
+use crate::weather::Coord;
+
+const ACCUMULATED_TEMPERATURE_API_BASE_URL: &str = "https://history.openweathermap.org/data/2.5/history/accumulated_temperature";
+const ACCUMULATED_PRECIPITATION_API_BASE_URL: &str = "https://history.openweathermap.org/data/2.5/history/accumulated_precipitation";
+const AGGREGATED_API_BASE_URL: &str = "https://history.openweathermap.org/data/2.5/aggregated";
+
+/// A client for the Statistical Weather Data and Accumulated Parameters APIs.
+///
+/// All values are returned in standard units (temperatures in Kelvin).
+///
+/// # Fields
+/// - `client`: The shared client that sends the requests.
+/// - `coord`: Coordinates the statistics are requested for.
+pub struct StatisticsClient {
+    client: OpenWeatherClient,
+    coord: Coord,
+}
+
+impl StatisticsClient {
+    pub fn new(coord: impl Into<Coord>, api_key: String) -> Self {
+        Self::with_client(coord, OpenWeatherClient::new("standard".to_string(), api_key))
+    }
+
+    /// Creates a client that shares the connection pool and API key of `client`.
+    pub fn with_client(coord: impl Into<Coord>, client: OpenWeatherClient) -> Self {
+        Self {
+            client,
+            coord: coord.into(),
+        }
+    }
+
+    pub fn set_coord(&mut self, coord: impl Into<Coord>) {
+        self.coord = coord.into();
+    }
+
+    pub fn get_coord(&self) -> Coord {
+        self.coord.clone()
+    }
+
+    /// Fetches the daily accumulated temperature between `start` and `end` (unix, UTC).
+    ///
+    /// Only temperatures above `threshold` (Kelvin) are accumulated; `None` uses the API default of 284 K.
+    pub async fn get_accumulated_temperature(&self, start: i64, end: i64, threshold: Option<f64>) -> Result<Vec<AccumulatedTemperature>, Box<dyn std::error::Error>> {
+        let mut query = self.range_query(start, end)?;
+        if let Some(threshold) = threshold {
+            query.push(("threshold", threshold.to_string()));
+        }
+
+        Ok(self.client.send_request(ACCUMULATED_TEMPERATURE_API_BASE_URL, query).await?.json().await?)
+    }
+
+    /// Fetches the daily accumulated precipitation between `start` and `end` (unix, UTC).
+    pub async fn get_accumulated_precipitation(&self, start: i64, end: i64) -> Result<Vec<AccumulatedPrecipitation>, Box<dyn std::error::Error>> {
+        let query = self.range_query(start, end)?;
+
+        Ok(self.client.send_request(ACCUMULATED_PRECIPITATION_API_BASE_URL, query).await?.json().await?)
+    }
+
+    /// Fetches the statistical weather for every day of the year.
+    pub async fn get_yearly_statistics(&self) -> Result<Vec<AggregatedWeather>, Box<dyn std::error::Error>> {
+        let url = format!("{}/year", AGGREGATED_API_BASE_URL);
+        let response: AggregatedResponse<Vec<AggregatedWeather>> = self.client.send_request(&url, self.base_query()).await?.json().await?;
+
+        Ok(response.result)
+    }
+
+    /// Fetches the statistical weather for one month of the year (1 to 12).
+    pub async fn get_monthly_statistics(&self, month: u32) -> Result<AggregatedWeather, Box<dyn std::error::Error>> {
+        if !(1..=12).contains(&month) {
+            return Err("Month must be between 1 and 12".into());
+        }
+
+        let url = format!("{}/month", AGGREGATED_API_BASE_URL);
+        let mut query = self.base_query();
+        query.push(("month", month.to_string()));
+        let response: AggregatedResponse<AggregatedWeather> = self.client.send_request(&url, query).await?.json().await?;
+
+        Ok(response.result)
+    }
+
+    /// Fetches the statistical weather for one day of the year.
+    pub async fn get_daily_statistics(&self, month: u32, day: u32) -> Result<AggregatedWeather, Box<dyn std::error::Error>> {
+        check_day_of_year(month, day)?;
+
+        let url = format!("{}/day", AGGREGATED_API_BASE_URL);
+        let mut query = self.base_query();
+        query.push(("month", month.to_string()));
+        query.push(("day", day.to_string()));
+        let response: AggregatedResponse<AggregatedWeather> = self.client.send_request(&url, query).await?.json().await?;
+
+        Ok(response.result)
+    }
+
+    fn base_query(&self) -> Vec<(&'static str, String)> {
+        WeatherQuery::Coord(self.coord.clone()).to_query()
+    }
+
+    fn range_query(&self, start: i64, end: i64) -> Result<Vec<(&'static str, String)>, String> {
+        if start > end {
+            return Err("Start must not be after end".to_string());
+        }
+
+        let mut query = self.base_query();
+        query.push(("start", start.to_string()));
+        query.push(("end", end.to_string()));
+        Ok(query)
+    }
+}
+
+/// Accepts every month/day pair that exists in some year, including February 29.
+fn check_day_of_year(month: u32, day: u32) -> Result<(), String> {
+    calendar::checked_days_from_civil(2024, month as i64, day as i64)
+        .map(|_| ())
+        .map_err(|_| format!("Invalid day of the year: {:02}-{:02}", month, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_of_year_allows_leap_days() {
+        assert!(check_day_of_year(2, 29).is_ok());
+        assert!(check_day_of_year(12, 31).is_ok());
+        assert!(check_day_of_year(1, 1).is_ok());
+    }
+
+    #[test]
+    fn day_of_year_rejects_days_that_never_exist() {
+        assert!(check_day_of_year(2, 30).is_err());
+        assert!(check_day_of_year(4, 31).is_err());
+        assert!(check_day_of_year(13, 1).is_err());
+        assert!(check_day_of_year(1, 0).is_err());
+    }
+}
@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+
+// region: Accumulated
+
+/// Represents the accumulated temperature of one day.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "date": "2025-07-13",
+///     "temp": 301.12,
+///     "count": 24
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccumulatedTemperature {
+    /// Date, YYYY-MM-DD
+    pub date: String,
+    /// Sum of the temperatures above the threshold accumulated since the start date, Kelvin
+    pub temp: f64,
+    /// Number of measurements used for the day
+    pub count: i32,
+}
+
+/// Represents the accumulated precipitation of one day.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "date": "2025-07-13",
+///     "rain": 12.4,
+///     "count": 24
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccumulatedPrecipitation {
+    /// Date, YYYY-MM-DD
+    pub date: String,
+    /// Precipitation accumulated since the start date, mm
+    pub rain: f64,
+    /// Number of measurements used for the day
+    pub count: i32,
+}
+
+// endregion
+
+// region: Statistics
+
+/// Represents the distribution of a weather parameter over the historical record.
+///
+/// Sample JSON
+/// ```json
+/// "pressure": {
+///     "min": 998,
+///     "max": 1031,
+///     "median": 1015,
+///     "mean": 1014.8,
+///     "p25": 1011,
+///     "p75": 1018,
+///     "st_dev": 5.3,
+///     "num": 744
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StatisticalValue {
+    /// Minimum value
+    pub min: f64,
+    /// Maximum value
+    pub max: f64,
+    /// Median value
+    pub median: f64,
+    /// Mean value
+    pub mean: f64,
+    /// 25th percentile
+    pub p25: f64,
+    /// 75th percentile
+    pub p75: f64,
+    /// Standard deviation
+    pub st_dev: f64,
+    /// Number of measurements
+    pub num: i64,
+}
+
+/// Represents the distribution of the temperature over the historical record, Kelvin.
+///
+/// Sample JSON
+/// ```json
+/// "temp": {
+///     "record_min": 283.15,
+///     "record_max": 309.26,
+///     "average_min": 290.7,
+///     "average_max": 302.8,
+///     "median": 296.5,
+///     "mean": 296.9,
+///     "p25": 293.4,
+///     "p75": 300.1,
+///     "st_dev": 4.1,
+///     "num": 744
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TemperatureStatistics {
+    /// Lowest temperature ever recorded
+    pub record_min: f64,
+    /// Highest temperature ever recorded
+    pub record_max: f64,
+    /// Average daily minimum
+    pub average_min: f64,
+    /// Average daily maximum
+    pub average_max: f64,
+    /// Median value
+    pub median: f64,
+    /// Mean value
+    pub mean: f64,
+    /// 25th percentile
+    pub p25: f64,
+    /// 75th percentile
+    pub p75: f64,
+    /// Standard deviation
+    pub st_dev: f64,
+    /// Number of measurements
+    pub num: i64,
+}
+
+/// Represents the statistical weather of one day or month of the year.
+///
+/// `day` is absent for monthly aggregations, and `month`/`day` identify the period
+/// within the year-long series.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AggregatedWeather {
+    /// Month of the year, from 1 to 12
+    pub month: i32,
+    /// Day of the month (absent for monthly aggregations)
+    pub day: Option<i32>,
+    /// Temperature, Kelvin
+    pub temp: TemperatureStatistics,
+    /// Atmospheric pressure, hPa
+    pub pressure: StatisticalValue,
+    /// Humidity, %
+    pub humidity: StatisticalValue,
+    /// Wind speed, m/s
+    pub wind: StatisticalValue,
+    /// Precipitation, mm
+    pub precipitation: StatisticalValue,
+    /// Cloudiness, %
+    pub clouds: StatisticalValue,
+    /// Average sunshine hours
+    pub sunshine_hours: Option<f64>,
+}
+
+/// Represents the envelope of the statistical aggregation endpoints.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AggregatedResponse<T> {
+    /// Aggregated values (a single period or a list of periods)
+    pub result: T,
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(mean: f64) -> serde_json::Value {
+        serde_json::json!({
+            "min": mean - 10.0, "max": mean + 10.0, "median": mean, "mean": mean,
+            "p25": mean - 5.0, "p75": mean + 5.0, "st_dev": 3.0, "num": 744
+        })
+    }
+
+    fn aggregated(month: i32, day: Option<i32>) -> serde_json::Value {
+        let mut weather = serde_json::json!({
+            "month": month,
+            "temp": {
+                "record_min": 283.15, "record_max": 309.26, "average_min": 290.7, "average_max": 302.8,
+                "median": 296.5, "mean": 296.9, "p25": 293.4, "p75": 300.1, "st_dev": 4.1, "num": 744
+            },
+            "pressure": value(1014.8),
+            "humidity": value(68.0),
+            "wind": value(3.9),
+            "precipitation": value(0.2),
+            "clouds": value(35.0),
+            "sunshine_hours": 9.4
+        });
+        if let Some(day) = day {
+            weather["day"] = day.into();
+        }
+        weather
+    }
+
+    #[test]
+    fn parses_a_monthly_aggregation() {
+        let json = serde_json::json!({ "cod": 200, "city_id": 5920450, "calctime": 0.31, "result": aggregated(7, None) });
+        let response: AggregatedResponse<AggregatedWeather> = serde_json::from_value(json).unwrap();
+
+        assert_eq!(response.result.month, 7);
+        assert_eq!(response.result.day, None);
+        assert_eq!(response.result.temp.record_max, 309.26);
+        assert_eq!(response.result.pressure.mean, 1014.8);
+        assert_eq!(response.result.sunshine_hours, Some(9.4));
+    }
+
+    #[test]
+    fn parses_a_yearly_aggregation() {
+        let json = serde_json::json!({ "result": [aggregated(1, Some(1)), aggregated(2, Some(29))] });
+        let response: AggregatedResponse<Vec<AggregatedWeather>> = serde_json::from_value(json).unwrap();
+
+        assert_eq!(response.result.len(), 2);
+        assert_eq!(response.result[1].day, Some(29));
+        assert_eq!(response.result[1].clouds.num, 744);
+    }
+
+    #[test]
+    fn parses_accumulated_series() {
+        let temperature: Vec<AccumulatedTemperature> = serde_json::from_str(
+            r#"[{"date":"2025-07-12","temp":289.4,"count":24},{"date":"2025-07-13","temp":301.12,"count":24}]"#,
+        ).unwrap();
+        assert_eq!(temperature[1].date, "2025-07-13");
+        assert_eq!(temperature[1].temp, 301.12);
+
+        let precipitation: Vec<AccumulatedPrecipitation> = serde_json::from_str(
+            r#"[{"date":"2025-07-13","rain":12.4,"count":24}]"#,
+        ).unwrap();
+        assert_eq!(precipitation[0].rain, 12.4);
+        assert_eq!(precipitation[0].count, 24);
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents the unit system of a request, which is also the unit system of its response.
+///
+/// - `Standard`: Kelvin, m/s (the API default)
+/// - `Metric`: °C, m/s
+/// - `Imperial`: °F, mph
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Units {
+    #[default]
+    Standard,
+    Metric,
+    Imperial,
+}
+
+impl Units {
+    /// Name used in the `units` query parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Units::Standard => "standard",
+            Units::Metric => "metric",
+            Units::Imperial => "imperial",
+        }
+    }
+
+    /// Reads a `units` parameter the way the API does: unknown names mean `Standard`.
+    pub fn from_api_name(name: &str) -> Self {
+        name.parse().unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Units {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Units {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "standard" => Ok(Units::Standard),
+            "metric" => Ok(Units::Metric),
+            "imperial" => Ok(Units::Imperial),
+            _ => Err(format!("Unknown units: {} (expected standard, metric or imperial)", value)),
+        }
+    }
+}
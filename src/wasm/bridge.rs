@@ -0,0 +1,91 @@
+//! The original JSON-string entry point, kept for existing callers of `get_weather_data`.
+//!
+//! New code should use the [`super::WasmWeatherClient`] class instead.
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::location::{Location, LocationClient};
+use crate::logging::debug_log;
+use crate::weather::WeatherClient;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WeatherRequestWasm {
+    pub zip: String,
+    pub country: String,
+    pub units: String,
+    pub api_key: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WeatherResponseWasm {
+    /// Resolved location, or `None` if the request failed before the lookup completed
+    pub location: Option<Location>,
+    pub weather: String,
+    pub error: Option<String>,
+}
+
+#[wasm_bindgen]
+pub async fn get_weather_data(request_json: &str) -> Result<String, JsValue> {
+    debug_log!("WASM function called");
+
+    let request: WeatherRequestWasm = serde_json::from_str(request_json)
+        .map_err(|e| {
+            debug_log!("JSON parse error: {}", e);
+            JsValue::from_str(&format!("Invalid request: {}", e))
+        })?;
+
+    debug_log!("Parsed request: zip [{}], country [{}], units [{}]", request.zip, request.country, request.units);
+
+    match fetch_weather_internal(request).await {
+        Ok(response) => {
+            debug_log!("Weather fetch successful");
+            serde_json::to_string(&response)
+                .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+        }
+        Err(e) => {
+            debug_log!("Weather fetch error: {}", e);
+            let error_response = WeatherResponseWasm {
+                location: None,
+                weather: String::new(),
+                error: Some(e),
+            };
+            serde_json::to_string(&error_response)
+                .map_err(|e| JsValue::from_str(&format!("Error serialization failed: {}", e)))
+        }
+    }
+}
+
+async fn fetch_weather_internal(request: WeatherRequestWasm) -> Result<WeatherResponseWasm, String> {
+    debug_log!("Creating location client");
+    debug_log!("Fetching location");
+
+    let location = LocationClient::new(
+        request.zip.clone(),
+        request.country.clone(),
+        request.api_key.clone(), )
+        .map_err(|e| format!("Location error: {}", e))?
+        .get_location()
+        .await
+        .map_err(|e| format!("Location error: {}", e))?;
+
+    debug_log!("Location found: {:?}", location);
+    debug_log!("Fetching weather");
+
+    let weather_response = WeatherClient::new(
+        location.clone(),
+        request.units.clone(),
+        request.api_key.clone(), )
+        .get_current_weather()
+        .await
+        .map_err(|e| format!("Weather error: {}", e))?;
+
+    debug_log!("Weather fetch complete");
+
+    Ok(WeatherResponseWasm {
+        location: Some(location),
+        weather: serde_json::to_string(&weather_response)
+            .map_err(|e| format!("Weather serialization error: {}", e))?,
+        error: None,
+    })
+}
\ No newline at end of file
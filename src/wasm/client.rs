@@ -0,0 +1,173 @@
+use wasm_bindgen::prelude::*;
+
+use super::error::{decode_error, invalid_input, to_js_error};
+use super::types::{
+    WasmAirQuality, WasmDailyForecastEntry, WasmForecastEntry, WasmHourlyForecastEntry, WasmLocation, WasmPlace, WasmWeatherResponse,
+};
+use crate::client::{check_city_name, check_daily_days, check_geocode_limit, check_hourly_count, OpenWeatherClient};
+use crate::geo::Coord;
+use crate::location::{CountryCode, PostalCode};
+use crate::query::WeatherQuery;
+use crate::units::Units;
+
+/// Weather client exposed to JavaScript as `WeatherClient`.
+///
+/// Every method returns a promise that rejects with an `OpenWeatherError`.
+///
+/// # Fields
+/// - `client`: The client that sends the requests.
+#[wasm_bindgen(js_name = WeatherClient)]
+pub struct WasmWeatherClient {
+    client: OpenWeatherClient,
+}
+
+#[wasm_bindgen(js_class = WeatherClient)]
+impl WasmWeatherClient {
+    /// Creates a client. `units` is `standard` (default), `metric` or `imperial`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(#[wasm_bindgen(js_name = apiKey)] api_key: String, units: Option<String>) -> Result<WasmWeatherClient, JsValue> {
+        let units = match units {
+            Some(units) => units.parse::<Units>().map_err(|e| invalid_input(&e))?,
+            None => Units::default(),
+        };
+
+        Ok(WasmWeatherClient {
+            client: OpenWeatherClient::new(units.to_string(), api_key),
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn units(&self) -> String {
+        self.client.get_units()
+    }
+
+    /// Resolves a postal code and country code into a location.
+    #[wasm_bindgen(js_name = geocodeZip)]
+    pub async fn geocode_zip(&self, zip: String, country: String) -> Result<WasmLocation, JsValue> {
+        let postal_code = postal_code(&zip, &country)?;
+        let location = self.client.geocode_zip(&postal_code).await.map_err(to_js_error)?;
+        Ok(location.into())
+    }
+
+    /// Fetches the current weather at a latitude and longitude.
+    #[wasm_bindgen(js_name = currentByCoord)]
+    pub async fn current_by_coord(&self, lat: f64, lon: f64) -> Result<WasmWeatherResponse, JsValue> {
+        self.current(WeatherQuery::Coord(coord(lat, lon)?)).await
+    }
+
+    /// Fetches the current weather for a postal code and country code.
+    #[wasm_bindgen(js_name = currentByZip)]
+    pub async fn current_by_zip(&self, zip: String, country: String) -> Result<WasmWeatherResponse, JsValue> {
+        self.current(WeatherQuery::Zip(postal_code(&zip, &country)?)).await
+    }
+
+    /// Fetches the current weather for a city name, optionally followed by state and country
+    /// codes (e.g. `London,GB`).
+    #[wasm_bindgen(js_name = currentByCity)]
+    pub async fn current_by_city(&self, name: String) -> Result<WasmWeatherResponse, JsValue> {
+        check(check_city_name(&name))?;
+        self.current(WeatherQuery::CityName(name)).await
+    }
+
+    /// Fetches the current weather for a location returned by `geocodeZip`.
+    #[wasm_bindgen(js_name = currentAt)]
+    pub async fn current_at(&self, location: &WasmLocation) -> Result<WasmWeatherResponse, JsValue> {
+        self.current(WeatherQuery::from(location.location())).await
+    }
+
+    /// Finds up to `limit` (1 to 5, default 5) places matching a city name, optionally followed
+    /// by state and country codes (e.g. `London,GB`).
+    pub async fn geocode(&self, name: String, limit: Option<u8>) -> Result<Vec<WasmPlace>, JsValue> {
+        let limit = limit.unwrap_or(5);
+        check(check_city_name(&name).and(check_geocode_limit(limit)))?;
+        let places = self.client.geocode(&name, limit).await.map_err(to_js_error)?;
+        Ok(places.into_iter().map(WasmPlace::from).collect())
+    }
+
+    /// Finds up to `limit` (1 to 5, default 5) named places near a latitude and longitude.
+    #[wasm_bindgen(js_name = reverseGeocode)]
+    pub async fn reverse_geocode(&self, lat: f64, lon: f64, limit: Option<u8>) -> Result<Vec<WasmPlace>, JsValue> {
+        let coord = coord(lat, lon)?;
+        let limit = limit.unwrap_or(5);
+        check(check_geocode_limit(limit))?;
+        let places = self.client.reverse_geocode(&coord, limit).await.map_err(to_js_error)?;
+        Ok(places.into_iter().map(WasmPlace::from).collect())
+    }
+
+    /// Fetches the current weather for a place returned by `geocode` or `reverseGeocode`.
+    #[wasm_bindgen(js_name = currentAtPlace)]
+    pub async fn current_at_place(&self, place: &WasmPlace) -> Result<WasmWeatherResponse, JsValue> {
+        self.current(WeatherQuery::Coord(place.place().coord.clone())).await
+    }
+
+    /// Fetches the 5 day forecast in 3-hour steps at a latitude and longitude (free plan).
+    pub async fn forecast(&self, lat: f64, lon: f64) -> Result<Vec<WasmForecastEntry>, JsValue> {
+        let query = WeatherQuery::Coord(coord(lat, lon)?);
+        let forecast = self.client.forecast(&query).await.map_err(to_js_error)?;
+        Ok(forecast.list.into_iter().map(WasmForecastEntry::from).collect())
+    }
+
+    /// Fetches the hourly forecast at a latitude and longitude, limited to `count` hours when
+    /// given (up to 96).
+    #[wasm_bindgen(js_name = hourlyForecast)]
+    pub async fn hourly_forecast(&self, lat: f64, lon: f64, count: Option<u8>) -> Result<Vec<WasmHourlyForecastEntry>, JsValue> {
+        let query = WeatherQuery::Coord(coord(lat, lon)?);
+        if let Some(count) = count {
+            check(check_hourly_count(count))?;
+        }
+        let forecast = self.client.hourly_forecast(&query, count).await.map_err(to_js_error)?;
+        Ok(forecast.list.into_iter().map(WasmHourlyForecastEntry::from).collect())
+    }
+
+    /// Fetches the daily forecast for `days` days (1 to 16) at a latitude and longitude.
+    #[wasm_bindgen(js_name = dailyForecast)]
+    pub async fn daily_forecast(&self, lat: f64, lon: f64, days: u8) -> Result<Vec<WasmDailyForecastEntry>, JsValue> {
+        let query = WeatherQuery::Coord(coord(lat, lon)?);
+        check(check_daily_days(days))?;
+        let forecast = self.client.daily_forecast(&query, days).await.map_err(to_js_error)?;
+        Ok(forecast.list.into_iter().map(WasmDailyForecastEntry::from).collect())
+    }
+
+    /// Fetches the current air pollution at a latitude and longitude.
+    #[wasm_bindgen(js_name = airPollution)]
+    pub async fn air_pollution(&self, lat: f64, lon: f64) -> Result<WasmAirQuality, JsValue> {
+        let coord = coord(lat, lon)?;
+        let pollution = self.client.air_pollution(&coord).await.map_err(to_js_error)?;
+        pollution
+            .list
+            .into_iter()
+            .next()
+            .map(WasmAirQuality::from)
+            .ok_or_else(|| decode_error("Air pollution response contains no data"))
+    }
+
+    /// Fetches the hourly air pollution forecast at a latitude and longitude.
+    #[wasm_bindgen(js_name = airPollutionForecast)]
+    pub async fn air_pollution_forecast(&self, lat: f64, lon: f64) -> Result<Vec<WasmAirQuality>, JsValue> {
+        let coord = coord(lat, lon)?;
+        let pollution = self.client.air_pollution_forecast(&coord).await.map_err(to_js_error)?;
+        Ok(pollution.list.into_iter().map(WasmAirQuality::from).collect())
+    }
+}
+
+impl WasmWeatherClient {
+    async fn current(&self, query: WeatherQuery) -> Result<WasmWeatherResponse, JsValue> {
+        let weather = self.client.current(&query).await.map_err(to_js_error)?;
+        Ok(weather.into())
+    }
+}
+
+/// Reports a failed argument check as invalid input, before any request is sent.
+fn check(result: Result<(), String>) -> Result<(), JsValue> {
+    result.map_err(|e| invalid_input(&e))
+}
+
+fn coord(lat: f64, lon: f64) -> Result<Coord, JsValue> {
+    Coord::new(lon, lat).map_err(|e| invalid_input(&e))
+}
+
+fn postal_code(zip: &str, country: &str) -> Result<PostalCode, JsValue> {
+    CountryCode::new(country)
+        .and_then(|country| PostalCode::new(zip, &country))
+        .map_err(|e| invalid_input(&e))
+}
@@ -0,0 +1,24 @@
+use wasm_bindgen::prelude::*;
+
+use crate::logging::{clear_logger, set_logger};
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console)]
+    fn log(s: &str);
+}
+
+/// Logger that writes to the browser console, for [`crate::logging::set_logger`].
+pub fn console_logger(message: &str) {
+    log(message);
+}
+
+/// Turns diagnostic logging to the browser console on or off (off by default).
+#[wasm_bindgen(js_name = setConsoleLogging)]
+pub fn set_console_logging(enabled: bool) {
+    if enabled {
+        set_logger(console_logger);
+    } else {
+        clear_logger();
+    }
+}
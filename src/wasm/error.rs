@@ -0,0 +1,166 @@
+use js_sys::Reflect;
+use wasm_bindgen::prelude::*;
+
+use crate::error::ApiError;
+
+#[wasm_bindgen(typescript_custom_section)]
+const OPEN_WEATHER_ERROR_TS: &str = r#"
+/**
+ * Error thrown (as a rejected promise) by every `WeatherClient` method.
+ *
+ * `status` is only set for `kind === "api"`, i.e. when the API answered with an error status.
+ */
+export interface OpenWeatherError extends Error {
+    name: "OpenWeatherError";
+    kind: "api" | "network" | "decode" | "invalid_input" | "unknown";
+    status?: number;
+}
+"#;
+
+/// Represents the category of an error surfaced to JavaScript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The API answered with an error status
+    Api,
+    /// The request could not be sent or the response could not be received
+    Network,
+    /// The response could not be parsed
+    Decode,
+    /// The arguments were rejected before any request was sent
+    InvalidInput,
+    /// Any other failure
+    Unknown,
+}
+
+impl ErrorKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorKind::Api => "api",
+            ErrorKind::Network => "network",
+            ErrorKind::Decode => "decode",
+            ErrorKind::InvalidInput => "invalid_input",
+            ErrorKind::Unknown => "unknown",
+        }
+    }
+
+    /// Classifies a client error, returning the HTTP status when there is one.
+    ///
+    /// JSON, XML and UTF-8 errors are [`ErrorKind::Decode`]. Any other error is
+    /// [`ErrorKind::Unknown`]; [`ErrorKind::InvalidInput`] is only reported for arguments the
+    /// bindings check themselves before calling the client.
+    pub fn classify(error: &(dyn std::error::Error + 'static)) -> (Self, Option<u16>) {
+        if let Some(error) = error.downcast_ref::<ApiError>() {
+            return (ErrorKind::Api, Some(error.status));
+        }
+        if let Some(error) = error.downcast_ref::<reqwest::Error>() {
+            if error.is_decode() {
+                return (ErrorKind::Decode, None);
+            }
+            if let Some(status) = error.status() {
+                return (ErrorKind::Api, Some(status.as_u16()));
+            }
+            return (ErrorKind::Network, None);
+        }
+        if is_decode_error(error) {
+            return (ErrorKind::Decode, None);
+        }
+        (ErrorKind::Unknown, None)
+    }
+}
+
+fn is_decode_error(error: &(dyn std::error::Error + 'static)) -> bool {
+    #[cfg(feature = "xml")]
+    if error.is::<quick_xml::DeError>() || error.is::<quick_xml::Error>() {
+        return true;
+    }
+
+    error.is::<serde_json::Error>()
+        || error.is::<std::str::Utf8Error>()
+        || error.is::<std::string::FromUtf8Error>()
+}
+
+/// Converts a client error into an `OpenWeatherError` JavaScript object.
+pub(crate) fn to_js_error(error: Box<dyn std::error::Error>) -> JsValue {
+    let (kind, status) = ErrorKind::classify(error.as_ref());
+    js_error(&error.to_string(), kind, status)
+}
+
+/// Builds an `OpenWeatherError` for arguments rejected before any request is sent.
+pub(crate) fn invalid_input(message: &str) -> JsValue {
+    js_error(message, ErrorKind::InvalidInput, None)
+}
+
+/// Builds an `OpenWeatherError` for a response that parsed but lacks the expected data.
+pub(crate) fn decode_error(message: &str) -> JsValue {
+    js_error(message, ErrorKind::Decode, None)
+}
+
+fn js_error(message: &str, kind: ErrorKind, status: Option<u16>) -> JsValue {
+    let error = js_sys::Error::new(message);
+    error.set_name("OpenWeatherError");
+
+    // Setting properties on a freshly created object cannot fail.
+    let _ = Reflect::set(&error, &"kind".into(), &kind.as_str().into());
+    if let Some(status) = status {
+        let _ = Reflect::set(&error, &"status".into(), &status.into());
+    }
+
+    error.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classify(error: Box<dyn std::error::Error>) -> (ErrorKind, Option<u16>) {
+        ErrorKind::classify(error.as_ref())
+    }
+
+    #[test]
+    fn api_errors_keep_their_status() {
+        let error = ApiError { status: 404, message: None };
+        assert_eq!(classify(error.into()), (ErrorKind::Api, Some(404)));
+    }
+
+    #[test]
+    fn parse_errors_are_decode_errors() {
+        let json = serde_json::from_str::<u32>("{").unwrap_err();
+        assert_eq!(classify(json.into()), (ErrorKind::Decode, None));
+
+        let utf8 = String::from_utf8(vec![0xff]).unwrap_err();
+        assert_eq!(classify(utf8.into()), (ErrorKind::Decode, None));
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn xml_errors_are_decode_errors() {
+        let malformed = crate::weather::xml::from_xml_str("<current><city/></current>").unwrap_err();
+        assert_eq!(classify(malformed), (ErrorKind::Decode, None));
+
+        // Well-formed, but the latitude is out of range.
+        let out_of_range = r#"<current><city id="1" name="x"><coord lon="0" lat="95"/><timezone>0</timezone><sun/></city>
+            <temperature value="1"/><wind><speed value="0"/></wind><clouds value="0"/>
+            <weather number="800" value="clear sky" icon="01d"/><lastupdate value="2025-01-01T00:00:00"/></current>"#;
+        let invalid = crate::weather::xml::from_xml_str(out_of_range).unwrap_err();
+        assert!(invalid.to_string().contains("Latitude"));
+        assert_eq!(classify(invalid), (ErrorKind::Decode, None));
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn forecast_xml_conversion_errors_are_decode_errors() {
+        // Well-formed, but the latitude is out of range.
+        let out_of_range = r#"<weatherdata><location><name>x</name><country>CA</country><timezone>0</timezone>
+            <location latitude="95" longitude="0" geobaseid="1"/></location><forecast/></weatherdata>"#;
+        let invalid = crate::forecast::xml::from_xml_str(out_of_range).unwrap_err();
+        assert!(invalid.to_string().contains("Latitude"));
+        assert_eq!(classify(invalid), (ErrorKind::Decode, None));
+    }
+
+    #[test]
+    fn unrecognized_errors_are_unknown() {
+        assert_eq!(classify("City name must not be empty".into()), (ErrorKind::Unknown, None));
+        assert_eq!(classify(std::io::Error::other("broken pipe").into()), (ErrorKind::Unknown, None));
+        assert_eq!(ErrorKind::Unknown.as_str(), "unknown");
+    }
+}
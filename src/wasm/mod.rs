@@ -0,0 +1,15 @@
+//! JavaScript bindings (`wasm` feature): `WeatherClient`, `Location` and `WeatherResponse`
+//! classes with TypeScript definitions, structured `OpenWeatherError` rejections, and the
+//! original `get_weather_data` entry point.
+
+pub mod types;
+pub mod client;
+pub mod error;
+pub mod bridge;
+pub mod console;
+
+pub use types::*;
+pub use client::WasmWeatherClient;
+pub use error::ErrorKind;
+pub use bridge::{get_weather_data, WeatherRequestWasm, WeatherResponseWasm};
+pub use console::{console_logger, set_console_logging};
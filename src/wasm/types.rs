@@ -0,0 +1,672 @@
+use wasm_bindgen::prelude::*;
+
+use crate::air_pollution::AirPollutionItem;
+use crate::forecast::{DailyForecastItem, ForecastItem, HourlyForecastItem};
+use crate::location::{Location, Place};
+use crate::weather::WeatherResponse;
+
+// region: Location
+
+/// A geocoded location, exposed to JavaScript as `Location`.
+#[wasm_bindgen(js_name = Location)]
+#[derive(Debug, Clone)]
+pub struct WasmLocation {
+    location: Location,
+}
+
+#[wasm_bindgen(js_class = Location)]
+impl WasmLocation {
+    /// ZIP or postal code
+    #[wasm_bindgen(getter)]
+    pub fn zip(&self) -> String {
+        self.location.zip.clone()
+    }
+
+    /// City or locality name
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.location.name.clone()
+    }
+
+    /// Latitude in decimal degrees
+    #[wasm_bindgen(getter)]
+    pub fn lat(&self) -> f64 {
+        self.location.coord.lat
+    }
+
+    /// Longitude in decimal degrees
+    #[wasm_bindgen(getter)]
+    pub fn lon(&self) -> f64 {
+        self.location.coord.lon
+    }
+
+    /// Two-letter country code (ISO 3166-1 alpha-2)
+    #[wasm_bindgen(getter)]
+    pub fn country(&self) -> String {
+        self.location.country.clone()
+    }
+}
+
+impl From<Location> for WasmLocation {
+    fn from(location: Location) -> Self {
+        WasmLocation { location }
+    }
+}
+
+impl WasmLocation {
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+}
+
+// endregion
+
+// region: WeatherResponse
+
+/// Current weather, exposed to JavaScript as `WeatherResponse`.
+///
+/// Values are in the units the client was created with. Fields the API did not report
+/// are `undefined`.
+#[wasm_bindgen(js_name = WeatherResponse)]
+#[derive(Debug, Clone)]
+pub struct WasmWeatherResponse {
+    weather: WeatherResponse,
+}
+
+#[wasm_bindgen(js_class = WeatherResponse)]
+impl WasmWeatherResponse {
+    /// City/location name
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.weather.name.clone()
+    }
+
+    /// Latitude in decimal degrees
+    #[wasm_bindgen(getter)]
+    pub fn lat(&self) -> f64 {
+        self.weather.coord.lat
+    }
+
+    /// Longitude in decimal degrees
+    #[wasm_bindgen(getter)]
+    pub fn lon(&self) -> f64 {
+        self.weather.coord.lon
+    }
+
+    /// Country code (ISO 3166-1 alpha-2)
+    #[wasm_bindgen(getter)]
+    pub fn country(&self) -> Option<String> {
+        self.weather.sys.country.clone()
+    }
+
+    /// Temperature
+    #[wasm_bindgen(getter)]
+    pub fn temperature(&self) -> Option<f64> {
+        self.weather.main.temp
+    }
+
+    /// Perceived temperature
+    #[wasm_bindgen(getter, js_name = feelsLike)]
+    pub fn feels_like(&self) -> Option<f64> {
+        self.weather.main.feels_like
+    }
+
+    /// Humidity, %
+    #[wasm_bindgen(getter)]
+    pub fn humidity(&self) -> Option<i32> {
+        self.weather.main.humidity
+    }
+
+    /// Atmospheric pressure at sea level, hPa
+    #[wasm_bindgen(getter)]
+    pub fn pressure(&self) -> Option<i32> {
+        self.weather.main.pressure
+    }
+
+    /// Wind speed
+    #[wasm_bindgen(getter, js_name = windSpeed)]
+    pub fn wind_speed(&self) -> f64 {
+        self.weather.wind.speed
+    }
+
+    /// Wind direction, degrees (meteorological)
+    #[wasm_bindgen(getter, js_name = windDirection)]
+    pub fn wind_direction(&self) -> Option<i32> {
+        self.weather.wind.deg
+    }
+
+    /// Wind gust
+    #[wasm_bindgen(getter, js_name = windGust)]
+    pub fn wind_gust(&self) -> Option<f64> {
+        self.weather.wind.gust
+    }
+
+    /// Cloudiness, %
+    #[wasm_bindgen(getter)]
+    pub fn clouds(&self) -> i32 {
+        self.weather.clouds.all
+    }
+
+    /// Visibility, meters
+    #[wasm_bindgen(getter)]
+    pub fn visibility(&self) -> Option<f64> {
+        self.weather.visibility.map(|visibility| visibility as f64)
+    }
+
+    /// Group of the main weather condition (Rain, Snow, Clouds etc.)
+    #[wasm_bindgen(getter)]
+    pub fn condition(&self) -> Option<String> {
+        self.weather.weather.first().map(|weather| weather.main.clone())
+    }
+
+    /// Description of the main weather condition
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> Option<String> {
+        self.weather.weather.first().map(|weather| weather.description.clone())
+    }
+
+    /// Icon ID of the main weather condition
+    #[wasm_bindgen(getter)]
+    pub fn icon(&self) -> Option<String> {
+        self.weather.weather.first().map(|weather| weather.icon.clone())
+    }
+
+    /// Time of data calculation, unix seconds, UTC
+    #[wasm_bindgen(getter)]
+    pub fn dt(&self) -> f64 {
+        self.weather.dt as f64
+    }
+
+    /// Shift in seconds from UTC
+    #[wasm_bindgen(getter)]
+    pub fn timezone(&self) -> i32 {
+        self.weather.timezone
+    }
+
+    /// Sunrise time, unix seconds, UTC
+    #[wasm_bindgen(getter)]
+    pub fn sunrise(&self) -> Option<f64> {
+        self.weather.sys.sunrise.map(|sunrise| sunrise as f64)
+    }
+
+    /// Sunset time, unix seconds, UTC
+    #[wasm_bindgen(getter)]
+    pub fn sunset(&self) -> Option<f64> {
+        self.weather.sys.sunset.map(|sunset| sunset as f64)
+    }
+
+    /// The full response as a plain object with the API's JSON shape.
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> Result<JsValue, JsValue> {
+        let json = serde_json::to_string(&self.weather).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        js_sys::JSON::parse(&json)
+    }
+}
+
+impl From<WeatherResponse> for WasmWeatherResponse {
+    fn from(weather: WeatherResponse) -> Self {
+        WasmWeatherResponse { weather }
+    }
+}
+
+impl WasmWeatherResponse {
+    pub fn weather(&self) -> &WeatherResponse {
+        &self.weather
+    }
+}
+
+// endregion
+
+// region: Place
+
+/// A place found by direct or reverse geocoding, exposed to JavaScript as `Place`.
+#[wasm_bindgen(js_name = Place)]
+#[derive(Debug, Clone)]
+pub struct WasmPlace {
+    place: Place,
+}
+
+#[wasm_bindgen(js_class = Place)]
+impl WasmPlace {
+    /// Name of the place
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.place.name.clone()
+    }
+
+    /// Latitude in decimal degrees
+    #[wasm_bindgen(getter)]
+    pub fn lat(&self) -> f64 {
+        self.place.coord.lat
+    }
+
+    /// Longitude in decimal degrees
+    #[wasm_bindgen(getter)]
+    pub fn lon(&self) -> f64 {
+        self.place.coord.lon
+    }
+
+    /// Two-letter country code (ISO 3166-1 alpha-2)
+    #[wasm_bindgen(getter)]
+    pub fn country(&self) -> String {
+        self.place.country.clone()
+    }
+
+    /// State or region, where available
+    #[wasm_bindgen(getter)]
+    pub fn state(&self) -> Option<String> {
+        self.place.state.clone()
+    }
+
+    /// Name of the place in `language` (ISO 639 code, e.g. `fr`), falling back to `name`.
+    #[wasm_bindgen(js_name = localName)]
+    pub fn local_name(&self, language: &str) -> String {
+        self.place.local_name(language).to_string()
+    }
+}
+
+impl From<Place> for WasmPlace {
+    fn from(place: Place) -> Self {
+        WasmPlace { place }
+    }
+}
+
+impl WasmPlace {
+    pub fn place(&self) -> &Place {
+        &self.place
+    }
+}
+
+// endregion
+
+// region: Forecast
+
+/// One 3-hour step of the 5 day forecast, exposed to JavaScript as `ForecastEntry`.
+#[wasm_bindgen(js_name = ForecastEntry)]
+#[derive(Debug, Clone)]
+pub struct WasmForecastEntry {
+    item: ForecastItem,
+}
+
+#[wasm_bindgen(js_class = ForecastEntry)]
+impl WasmForecastEntry {
+    /// Time of the forecast, unix seconds, UTC
+    #[wasm_bindgen(getter)]
+    pub fn dt(&self) -> f64 {
+        self.item.dt as f64
+    }
+
+    /// Temperature
+    #[wasm_bindgen(getter)]
+    pub fn temperature(&self) -> Option<f64> {
+        self.item.main.temp
+    }
+
+    /// Perceived temperature
+    #[wasm_bindgen(getter, js_name = feelsLike)]
+    pub fn feels_like(&self) -> Option<f64> {
+        self.item.main.feels_like
+    }
+
+    /// Humidity, %
+    #[wasm_bindgen(getter)]
+    pub fn humidity(&self) -> Option<i32> {
+        self.item.main.humidity
+    }
+
+    /// Atmospheric pressure at sea level, hPa
+    #[wasm_bindgen(getter)]
+    pub fn pressure(&self) -> Option<i32> {
+        self.item.main.pressure
+    }
+
+    /// Wind speed
+    #[wasm_bindgen(getter, js_name = windSpeed)]
+    pub fn wind_speed(&self) -> f64 {
+        self.item.wind.speed
+    }
+
+    /// Wind direction, degrees (meteorological)
+    #[wasm_bindgen(getter, js_name = windDirection)]
+    pub fn wind_direction(&self) -> Option<i32> {
+        self.item.wind.deg
+    }
+
+    /// Cloudiness, %
+    #[wasm_bindgen(getter)]
+    pub fn clouds(&self) -> i32 {
+        self.item.clouds.all
+    }
+
+    /// Probability of precipitation, from 0 to 1
+    #[wasm_bindgen(getter)]
+    pub fn pop(&self) -> Option<f64> {
+        self.item.pop
+    }
+
+    /// Group of the main weather condition (Rain, Snow, Clouds etc.)
+    #[wasm_bindgen(getter)]
+    pub fn condition(&self) -> Option<String> {
+        self.item.weather.first().map(|weather| weather.main.clone())
+    }
+
+    /// Description of the main weather condition
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> Option<String> {
+        self.item.weather.first().map(|weather| weather.description.clone())
+    }
+
+    /// Icon ID of the main weather condition
+    #[wasm_bindgen(getter)]
+    pub fn icon(&self) -> Option<String> {
+        self.item.weather.first().map(|weather| weather.icon.clone())
+    }
+
+    /// Rain volume over the 3 hours, mm
+    #[wasm_bindgen(getter)]
+    pub fn rain(&self) -> Option<f64> {
+        self.item.rain.as_ref().and_then(|rain| rain.three_hours)
+    }
+
+    /// Snow volume over the 3 hours, mm
+    #[wasm_bindgen(getter)]
+    pub fn snow(&self) -> Option<f64> {
+        self.item.snow.as_ref().and_then(|snow| snow.three_hours)
+    }
+}
+
+impl From<ForecastItem> for WasmForecastEntry {
+    fn from(item: ForecastItem) -> Self {
+        WasmForecastEntry { item }
+    }
+}
+
+/// One hour of the hourly forecast, exposed to JavaScript as `HourlyForecastEntry`.
+#[wasm_bindgen(js_name = HourlyForecastEntry)]
+#[derive(Debug, Clone)]
+pub struct WasmHourlyForecastEntry {
+    item: HourlyForecastItem,
+}
+
+#[wasm_bindgen(js_class = HourlyForecastEntry)]
+impl WasmHourlyForecastEntry {
+    /// Time of the forecast, unix seconds, UTC
+    #[wasm_bindgen(getter)]
+    pub fn dt(&self) -> f64 {
+        self.item.dt as f64
+    }
+
+    /// Temperature
+    #[wasm_bindgen(getter)]
+    pub fn temperature(&self) -> Option<f64> {
+        self.item.main.temp
+    }
+
+    /// Perceived temperature
+    #[wasm_bindgen(getter, js_name = feelsLike)]
+    pub fn feels_like(&self) -> Option<f64> {
+        self.item.main.feels_like
+    }
+
+    /// Humidity, %
+    #[wasm_bindgen(getter)]
+    pub fn humidity(&self) -> Option<i32> {
+        self.item.main.humidity
+    }
+
+    /// Atmospheric pressure at sea level, hPa
+    #[wasm_bindgen(getter)]
+    pub fn pressure(&self) -> Option<i32> {
+        self.item.main.pressure
+    }
+
+    /// Wind speed
+    #[wasm_bindgen(getter, js_name = windSpeed)]
+    pub fn wind_speed(&self) -> f64 {
+        self.item.wind.speed
+    }
+
+    /// Wind direction, degrees (meteorological)
+    #[wasm_bindgen(getter, js_name = windDirection)]
+    pub fn wind_direction(&self) -> Option<i32> {
+        self.item.wind.deg
+    }
+
+    /// Cloudiness, %
+    #[wasm_bindgen(getter)]
+    pub fn clouds(&self) -> i32 {
+        self.item.clouds.all
+    }
+
+    /// Probability of precipitation, from 0 to 1
+    #[wasm_bindgen(getter)]
+    pub fn pop(&self) -> Option<f64> {
+        self.item.pop
+    }
+
+    /// Group of the main weather condition (Rain, Snow, Clouds etc.)
+    #[wasm_bindgen(getter)]
+    pub fn condition(&self) -> Option<String> {
+        self.item.weather.first().map(|weather| weather.main.clone())
+    }
+
+    /// Description of the main weather condition
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> Option<String> {
+        self.item.weather.first().map(|weather| weather.description.clone())
+    }
+
+    /// Icon ID of the main weather condition
+    #[wasm_bindgen(getter)]
+    pub fn icon(&self) -> Option<String> {
+        self.item.weather.first().map(|weather| weather.icon.clone())
+    }
+}
+
+impl From<HourlyForecastItem> for WasmHourlyForecastEntry {
+    fn from(item: HourlyForecastItem) -> Self {
+        WasmHourlyForecastEntry { item }
+    }
+}
+
+/// One day of the daily forecast, exposed to JavaScript as `DailyForecastEntry`.
+#[wasm_bindgen(js_name = DailyForecastEntry)]
+#[derive(Debug, Clone)]
+pub struct WasmDailyForecastEntry {
+    item: DailyForecastItem,
+}
+
+#[wasm_bindgen(js_class = DailyForecastEntry)]
+impl WasmDailyForecastEntry {
+    /// Time of the forecast (midday), unix seconds, UTC
+    #[wasm_bindgen(getter)]
+    pub fn dt(&self) -> f64 {
+        self.item.dt as f64
+    }
+
+    /// Daytime temperature
+    #[wasm_bindgen(getter)]
+    pub fn temperature(&self) -> f64 {
+        self.item.temp.day
+    }
+
+    /// Minimum daily temperature
+    #[wasm_bindgen(getter, js_name = temperatureMin)]
+    pub fn temperature_min(&self) -> f64 {
+        self.item.temp.min
+    }
+
+    /// Maximum daily temperature
+    #[wasm_bindgen(getter, js_name = temperatureMax)]
+    pub fn temperature_max(&self) -> f64 {
+        self.item.temp.max
+    }
+
+    /// Perceived daytime temperature
+    #[wasm_bindgen(getter, js_name = feelsLike)]
+    pub fn feels_like(&self) -> f64 {
+        self.item.feels_like.day
+    }
+
+    /// Humidity, %
+    #[wasm_bindgen(getter)]
+    pub fn humidity(&self) -> Option<i32> {
+        self.item.humidity
+    }
+
+    /// Atmospheric pressure at sea level, hPa
+    #[wasm_bindgen(getter)]
+    pub fn pressure(&self) -> Option<i32> {
+        self.item.pressure
+    }
+
+    /// Wind speed
+    #[wasm_bindgen(getter, js_name = windSpeed)]
+    pub fn wind_speed(&self) -> f64 {
+        self.item.speed
+    }
+
+    /// Wind direction, degrees (meteorological)
+    #[wasm_bindgen(getter, js_name = windDirection)]
+    pub fn wind_direction(&self) -> Option<i32> {
+        self.item.deg
+    }
+
+    /// Cloudiness, %
+    #[wasm_bindgen(getter)]
+    pub fn clouds(&self) -> i32 {
+        self.item.clouds
+    }
+
+    /// Probability of precipitation, from 0 to 1
+    #[wasm_bindgen(getter)]
+    pub fn pop(&self) -> Option<f64> {
+        self.item.pop
+    }
+
+    /// Sunrise time, unix seconds, UTC
+    #[wasm_bindgen(getter)]
+    pub fn sunrise(&self) -> Option<f64> {
+        self.item.sunrise.map(|sunrise| sunrise as f64)
+    }
+
+    /// Sunset time, unix seconds, UTC
+    #[wasm_bindgen(getter)]
+    pub fn sunset(&self) -> Option<f64> {
+        self.item.sunset.map(|sunset| sunset as f64)
+    }
+
+    /// Group of the main weather condition (Rain, Snow, Clouds etc.)
+    #[wasm_bindgen(getter)]
+    pub fn condition(&self) -> Option<String> {
+        self.item.weather.first().map(|weather| weather.main.clone())
+    }
+
+    /// Description of the main weather condition
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> Option<String> {
+        self.item.weather.first().map(|weather| weather.description.clone())
+    }
+
+    /// Icon ID of the main weather condition
+    #[wasm_bindgen(getter)]
+    pub fn icon(&self) -> Option<String> {
+        self.item.weather.first().map(|weather| weather.icon.clone())
+    }
+}
+
+impl From<DailyForecastItem> for WasmDailyForecastEntry {
+    fn from(item: DailyForecastItem) -> Self {
+        WasmDailyForecastEntry { item }
+    }
+}
+
+// endregion
+
+// region: AirQuality
+
+/// Air pollution at one timestamp, exposed to JavaScript as `AirQuality`.
+///
+/// Concentrations are in μg/m³.
+#[wasm_bindgen(js_name = AirQuality)]
+#[derive(Debug, Clone)]
+pub struct WasmAirQuality {
+    item: AirPollutionItem,
+}
+
+#[wasm_bindgen(js_class = AirQuality)]
+impl WasmAirQuality {
+    /// Time of the data, unix seconds, UTC
+    #[wasm_bindgen(getter)]
+    pub fn dt(&self) -> f64 {
+        self.item.dt as f64
+    }
+
+    /// Air Quality Index, from 1 (good) to 5 (very poor)
+    #[wasm_bindgen(getter)]
+    pub fn aqi(&self) -> u8 {
+        self.item.main.aqi.into()
+    }
+
+    /// Description of the Air Quality Index (e.g. "Fair")
+    #[wasm_bindgen(getter, js_name = aqiDescription)]
+    pub fn aqi_description(&self) -> String {
+        self.item.main.aqi.to_string()
+    }
+
+    /// Carbon monoxide
+    #[wasm_bindgen(getter)]
+    pub fn co(&self) -> f64 {
+        self.item.components.co
+    }
+
+    /// Nitrogen monoxide
+    #[wasm_bindgen(getter)]
+    pub fn no(&self) -> f64 {
+        self.item.components.no
+    }
+
+    /// Nitrogen dioxide
+    #[wasm_bindgen(getter)]
+    pub fn no2(&self) -> f64 {
+        self.item.components.no2
+    }
+
+    /// Ozone
+    #[wasm_bindgen(getter)]
+    pub fn o3(&self) -> f64 {
+        self.item.components.o3
+    }
+
+    /// Sulphur dioxide
+    #[wasm_bindgen(getter)]
+    pub fn so2(&self) -> f64 {
+        self.item.components.so2
+    }
+
+    /// Fine particulate matter (diameter up to 2.5 μm)
+    #[wasm_bindgen(getter)]
+    pub fn pm2_5(&self) -> f64 {
+        self.item.components.pm2_5
+    }
+
+    /// Coarse particulate matter (diameter up to 10 μm)
+    #[wasm_bindgen(getter)]
+    pub fn pm10(&self) -> f64 {
+        self.item.components.pm10
+    }
+
+    /// Ammonia
+    #[wasm_bindgen(getter)]
+    pub fn nh3(&self) -> f64 {
+        self.item.components.nh3
+    }
+}
+
+impl From<AirPollutionItem> for WasmAirQuality {
+    fn from(item: AirPollutionItem) -> Self {
+        WasmAirQuality { item }
+    }
+}
+
+// endregion
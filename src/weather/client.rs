@@ -1,57 +1,70 @@
-﻿use super::types::WeatherResponse;
-use crate::location::Location;
+﻿use super::types::{BoundingBox, CityWeather, WeatherResponse};
+use crate::client::OpenWeatherClient;
+use crate::geo::Coord;
+use crate::query::WeatherQuery;
+use crate::units::Units;
 
-const WEATHER_API_BASE_URL: &str = "https://api.openweathermap.org/data/2.5/weather";
-
-/// A client for interacting with a location-based geocoding API.
+/// A client for the current weather of a single location.
 ///
-/// This struct encapsulates an HTTP client along with location query parameters,
-/// including ZIP code, country code, and an API key for authentication.
-/// It provides methods to update these parameters and to request location data
-/// asynchronously from the geocoding service.
+/// This is a thin wrapper around [`OpenWeatherClient`] bound to one set of coordinates.
+/// Use [`OpenWeatherClient`] directly to query several places with one connection pool.
 ///
 /// # Fields
-/// - `client`: The underlying HTTP client used to send requests.
-/// - `api_key`: API key for authenticating requests.
-/// - `zip`: ZIP or postal code for location lookup.
-/// - `country`: Country code (ISO 3166-1 alpha-2 format).
-///
-/// # Usage
-/// Create via `LocationClient::new` with ZIP, country, and API key.
-/// Use `get_location` to asynchronously fetch location details.
+/// - `client`: The shared client that sends the requests.
+/// - `coord`: Coordinates the weather is requested for.
 pub struct WeatherClient {
-    client: reqwest::Client,
-    location: Location,
-    units: String,
-    api_key: String,
+    client: OpenWeatherClient,
+    coord: Coord,
 }
 
 impl WeatherClient {
-    pub fn new(location: Location, units: String, api_key: String) -> Self {
+    pub fn new(coord: impl Into<Coord>, units: String, api_key: String) -> Self {
+        Self::with_client(coord, OpenWeatherClient::new(units, api_key))
+    }
+
+    /// Creates a client that shares the connection pool, units and API key of `client`.
+    pub fn with_client(coord: impl Into<Coord>, client: OpenWeatherClient) -> Self {
         Self {
-            client: reqwest::Client::new(),
-            location,
-            units: units.clone(),
-            api_key,
+            client,
+            coord: coord.into(),
         }
     }
 
+    /// Unit system of the responses, for use with the derived metrics and wind helpers.
+    pub fn units(&self) -> Units {
+        self.client.units()
+    }
+
     pub async fn get_current_weather(&self) -> Result<WeatherResponse, Box<dyn std::error::Error>> {
-        let response = self.client
-            .get(WEATHER_API_BASE_URL)
-            .query(&[
-                ("lat", self.location.lat.to_string()),
-                ("lon", self.location.lon.to_string()),
-                ("units", self.units.to_string()),
-                ("appid", self.api_key.clone())
-            ])
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(format!("API request failed with status: {}", response.status()).into());
-        }
+        self.client.current(&self.query()).await
+    }
 
-        Ok(response.json().await?)
+    /// Fetches the current weather using `mode=xml` and parses it into a [`WeatherResponse`].
+    ///
+    /// See [`super::xml::from_xml_str`] for how XML-only fields are mapped.
+    #[cfg(feature = "xml")]
+    pub async fn get_current_weather_xml(&self) -> Result<WeatherResponse, Box<dyn std::error::Error>> {
+        self.client.current_xml(&self.query()).await
     }
-}
\ No newline at end of file
+
+    /// Fetches the current weather using `mode=html` and returns the raw HTML widget markup.
+    pub async fn get_current_weather_html(&self) -> Result<String, Box<dyn std::error::Error>> {
+        self.client.current_html(&self.query()).await
+    }
+
+    /// Fetches the current weather for up to `count` cities (at most 50) around the client's location.
+    pub async fn get_cities_in_circle(&self, count: u8) -> Result<Vec<CityWeather>, Box<dyn std::error::Error>> {
+        self.client.cities_in_circle(&self.coord, count).await
+    }
+
+    /// Fetches the current weather for the cities inside `bbox`, filtered by map `zoom` level.
+    ///
+    /// The client's location is not used by this query.
+    pub async fn get_cities_in_box(&self, bbox: &BoundingBox, zoom: u8) -> Result<Vec<CityWeather>, Box<dyn std::error::Error>> {
+        self.client.cities_in_box(bbox, zoom).await
+    }
+
+    fn query(&self) -> WeatherQuery {
+        WeatherQuery::Coord(self.coord.clone())
+    }
+}
@@ -0,0 +1,254 @@
+//! Meteorological metrics derived from temperature, humidity and wind speed.
+//!
+//! Every function takes and returns values in the [`Units`] of the API request, usually
+//! `OpenWeatherClient::units()`: Kelvin, °C or °F for temperatures and m/s or mph for wind.
+
+use super::types::{Main, WeatherResponse};
+use crate::units::Units;
+
+/// Dew point temperature (Magnus formula).
+pub fn dew_point(temp: f64, humidity: f64, units: Units) -> f64 {
+    let celsius = to_celsius(temp, units);
+    from_celsius(dew_point_celsius(celsius, humidity), units)
+}
+
+/// Heat index (NWS Rothfusz regression with Steadman's simple formula for mild conditions).
+pub fn heat_index(temp: f64, humidity: f64, units: Units) -> f64 {
+    let fahrenheit = to_celsius(temp, units) * 9.0 / 5.0 + 32.0;
+
+    let simple = 0.5 * (fahrenheit + 61.0 + (fahrenheit - 68.0) * 1.2 + humidity * 0.094);
+    let index = if (simple + fahrenheit) / 2.0 < 80.0 {
+        simple
+    } else {
+        let mut index = -42.379 + 2.049_015_23 * fahrenheit + 10.143_331_27 * humidity
+            - 0.224_755_41 * fahrenheit * humidity
+            - 0.006_837_83 * fahrenheit * fahrenheit
+            - 0.054_817_17 * humidity * humidity
+            + 0.001_228_74 * fahrenheit * fahrenheit * humidity
+            + 0.000_852_82 * fahrenheit * humidity * humidity
+            - 0.000_001_99 * fahrenheit * fahrenheit * humidity * humidity;
+
+        if humidity < 13.0 && (80.0..=112.0).contains(&fahrenheit) {
+            index -= (13.0 - humidity) / 4.0 * ((17.0 - (fahrenheit - 95.0).abs()) / 17.0).sqrt();
+        } else if humidity > 85.0 && (80.0..=87.0).contains(&fahrenheit) {
+            index += (humidity - 85.0) / 10.0 * (87.0 - fahrenheit) / 5.0;
+        }
+        index
+    };
+
+    from_celsius((index - 32.0) * 5.0 / 9.0, units)
+}
+
+/// Humidex (Environment Canada). The index is a dimensionless number on the Celsius scale
+/// and is not converted to `units`.
+pub fn humidex(temp: f64, humidity: f64, units: Units) -> f64 {
+    let celsius = to_celsius(temp, units);
+    let dew_point_kelvin = dew_point_celsius(celsius, humidity) + 273.15;
+    let vapour_pressure = 6.11 * (5417.7530 * (1.0 / 273.16 - 1.0 / dew_point_kelvin)).exp();
+
+    celsius + 0.5555 * (vapour_pressure - 10.0)
+}
+
+/// Wind chill (Environment Canada / NWS 2001 formula).
+///
+/// Returns `None` outside the formula's validity range: temperatures above 10 °C or wind
+/// speeds below 4.8 km/h.
+pub fn wind_chill(temp: f64, wind_speed: f64, units: Units) -> Option<f64> {
+    let celsius = to_celsius(temp, units);
+    let kmh = to_meters_per_second(wind_speed, units) * 3.6;
+    if celsius > 10.0 || kmh < 4.8 {
+        return None;
+    }
+
+    let factor = kmh.powf(0.16);
+    Some(from_celsius(13.12 + 0.6215 * celsius - 11.37 * factor + 0.3965 * celsius * factor, units))
+}
+
+/// Apparent temperature (Australian Bureau of Meteorology, Steadman's formula without radiation).
+pub fn apparent_temperature(temp: f64, humidity: f64, wind_speed: f64, units: Units) -> f64 {
+    let celsius = to_celsius(temp, units);
+    let vapour_pressure = humidity / 100.0 * 6.105 * (17.27 * celsius / (237.7 + celsius)).exp();
+    let speed = to_meters_per_second(wind_speed, units);
+
+    from_celsius(celsius + 0.33 * vapour_pressure - 0.70 * speed - 4.00, units)
+}
+
+/// Absolute humidity in g/m³ (independent of `units`, which only applies to `temp`).
+pub fn absolute_humidity(temp: f64, humidity: f64, units: Units) -> f64 {
+    let celsius = to_celsius(temp, units);
+    6.112 * (17.67 * celsius / (celsius + 243.5)).exp() * humidity * 2.1674 / (273.15 + celsius)
+}
+
+fn dew_point_celsius(celsius: f64, humidity: f64) -> f64 {
+    const B: f64 = 17.62;
+    const C: f64 = 243.12;
+    let gamma = (humidity.max(f64::MIN_POSITIVE) / 100.0).ln() + B * celsius / (C + celsius);
+    C * gamma / (B - gamma)
+}
+
+fn to_celsius(temp: f64, units: Units) -> f64 {
+    match units {
+        Units::Metric => temp,
+        Units::Imperial => (temp - 32.0) * 5.0 / 9.0,
+        Units::Standard => temp - 273.15,
+    }
+}
+
+fn from_celsius(celsius: f64, units: Units) -> f64 {
+    match units {
+        Units::Metric => celsius,
+        Units::Imperial => celsius * 9.0 / 5.0 + 32.0,
+        Units::Standard => celsius + 273.15,
+    }
+}
+
+fn to_meters_per_second(speed: f64, units: Units) -> f64 {
+    match units {
+        Units::Imperial => speed * 0.447_04,
+        Units::Standard | Units::Metric => speed,
+    }
+}
+
+impl Main {
+    /// Dew point in `units`, or `None` if temperature or humidity is missing.
+    pub fn dew_point(&self, units: Units) -> Option<f64> {
+        let (temp, humidity) = self.temp_and_humidity()?;
+        Some(dew_point(temp, humidity, units))
+    }
+
+    /// Heat index in `units`, or `None` if temperature or humidity is missing.
+    pub fn heat_index(&self, units: Units) -> Option<f64> {
+        let (temp, humidity) = self.temp_and_humidity()?;
+        Some(heat_index(temp, humidity, units))
+    }
+
+    /// Humidex (Celsius scale), or `None` if temperature or humidity is missing.
+    pub fn humidex(&self, units: Units) -> Option<f64> {
+        let (temp, humidity) = self.temp_and_humidity()?;
+        Some(humidex(temp, humidity, units))
+    }
+
+    /// Wind chill in `units` for a `wind_speed` in the same units, or `None` if temperature
+    /// is missing or the conditions are outside the formula's validity range.
+    pub fn wind_chill(&self, wind_speed: f64, units: Units) -> Option<f64> {
+        wind_chill(self.temp?, wind_speed, units)
+    }
+
+    /// Apparent temperature in `units` for a `wind_speed` in the same units, or `None` if
+    /// temperature or humidity is missing.
+    pub fn apparent_temperature(&self, wind_speed: f64, units: Units) -> Option<f64> {
+        let (temp, humidity) = self.temp_and_humidity()?;
+        Some(apparent_temperature(temp, humidity, wind_speed, units))
+    }
+
+    /// Absolute humidity in g/m³, or `None` if temperature or humidity is missing.
+    pub fn absolute_humidity(&self, units: Units) -> Option<f64> {
+        let (temp, humidity) = self.temp_and_humidity()?;
+        Some(absolute_humidity(temp, humidity, units))
+    }
+
+    fn temp_and_humidity(&self) -> Option<(f64, f64)> {
+        Some((self.temp?, self.humidity? as f64))
+    }
+}
+
+impl WeatherResponse {
+    /// Dew point in `units`, or `None` if temperature or humidity is missing.
+    pub fn dew_point(&self, units: Units) -> Option<f64> {
+        self.main.dew_point(units)
+    }
+
+    /// Heat index in `units`, or `None` if temperature or humidity is missing.
+    pub fn heat_index(&self, units: Units) -> Option<f64> {
+        self.main.heat_index(units)
+    }
+
+    /// Humidex (Celsius scale), or `None` if temperature or humidity is missing.
+    pub fn humidex(&self, units: Units) -> Option<f64> {
+        self.main.humidex(units)
+    }
+
+    /// Wind chill in `units`, or `None` if temperature is missing or the conditions are
+    /// outside the formula's validity range.
+    pub fn wind_chill(&self, units: Units) -> Option<f64> {
+        self.main.wind_chill(self.wind.speed, units)
+    }
+
+    /// Apparent temperature in `units`, or `None` if temperature or humidity is missing.
+    pub fn apparent_temperature(&self, units: Units) -> Option<f64> {
+        self.main.apparent_temperature(self.wind.speed, units)
+    }
+
+    /// Absolute humidity in g/m³, or `None` if temperature or humidity is missing.
+    pub fn absolute_humidity(&self, units: Units) -> Option<f64> {
+        self.main.absolute_humidity(units)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!((actual - expected).abs() <= tolerance, "expected {} ± {}, got {}", expected, tolerance, actual);
+    }
+
+    #[test]
+    fn dew_point_reference_values() {
+        // 25 °C at 60 % gives a dew point of 16.7 °C.
+        assert_close(dew_point(25.0, 60.0, Units::Metric), 16.69, 0.05);
+        assert_close(dew_point(77.0, 60.0, Units::Imperial), 62.05, 0.1);
+        assert_close(dew_point(298.15, 60.0, Units::Standard), 289.84, 0.05);
+        // Saturated air: the dew point is the air temperature.
+        assert_close(dew_point(10.0, 100.0, Units::Metric), 10.0, 0.01);
+    }
+
+    #[test]
+    fn heat_index_matches_nws_table() {
+        // NWS heat index chart: 90 °F at 70 % reads 106 °F, 80 °F at 40 % reads 80 °F.
+        assert_close(heat_index(90.0, 70.0, Units::Imperial), 106.0, 0.5);
+        assert_close(heat_index(80.0, 40.0, Units::Imperial), 80.0, 0.5);
+        assert_close(heat_index(32.22, 70.0, Units::Metric), 41.1, 0.3);
+    }
+
+    #[test]
+    fn humidex_matches_environment_canada_table() {
+        // 30 °C at 70 % reads 41 on the Environment Canada humidex table.
+        assert_close(humidex(30.0, 70.0, Units::Metric), 41.0, 0.6);
+        assert_close(humidex(86.0, 70.0, Units::Imperial), 41.0, 0.6);
+    }
+
+    #[test]
+    fn wind_chill_matches_environment_canada_table() {
+        // -20 °C with a 30 km/h wind reads -33 on the Environment Canada wind chill table.
+        assert_close(wind_chill(-20.0, 30.0 / 3.6, Units::Metric).unwrap(), -32.6, 0.1);
+        // -10 °C with a 20 km/h wind reads -18.
+        assert_close(wind_chill(-10.0, 20.0 / 3.6, Units::Metric).unwrap(), -17.9, 0.1);
+        assert_eq!(wind_chill(15.0, 10.0, Units::Metric), None);
+        assert_eq!(wind_chill(-5.0, 1.0, Units::Metric), None);
+    }
+
+    #[test]
+    fn apparent_temperature_reference_value() {
+        // 25 °C, 50 % and 2 m/s: vapour pressure 15.79 hPa.
+        assert_close(apparent_temperature(25.0, 50.0, 2.0, Units::Metric), 24.81, 0.05);
+    }
+
+    #[test]
+    fn absolute_humidity_reference_value() {
+        // 20 °C at 50 % holds about 8.6 g/m³ of water vapour, whatever the units.
+        assert_close(absolute_humidity(20.0, 50.0, Units::Metric), 8.64, 0.05);
+        assert_close(absolute_humidity(68.0, 50.0, Units::Imperial), 8.64, 0.05);
+    }
+
+    #[test]
+    fn main_methods_need_temperature_and_humidity() {
+        let main = Main::new(Some(25.0), None, None, None, Some(1013), Some(60), None, None).unwrap();
+        assert_close(main.dew_point(Units::Metric).unwrap(), 16.69, 0.05);
+        assert!(main.apparent_temperature(2.0, Units::Metric).is_some());
+
+        let dry = Main::new(Some(25.0), None, None, None, None, None, None, None).unwrap();
+        assert_eq!(dry.dew_point(Units::Metric), None);
+        assert_eq!(dry.humidex(Units::Metric), None);
+    }
+}
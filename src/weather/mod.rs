@@ -1,5 +1,10 @@
 ﻿pub mod types;
 pub mod client;
+pub mod derived;
+pub mod wind;
+pub mod time;
+#[cfg(feature = "xml")]
+pub mod xml;
 
 pub use types::*;
 pub use client::WeatherClient;
\ No newline at end of file
@@ -0,0 +1,148 @@
+//! Timestamp and time zone helpers for current weather data.
+//!
+//! The API reports times as Unix timestamps (UTC) and the location's time zone as an offset
+//! in seconds. With the `chrono` feature enabled these can be read as `DateTime<Utc>` or as
+//! `DateTime<FixedOffset>` in the location's local time.
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, FixedOffset, Utc};
+
+#[cfg(feature = "chrono")]
+use super::types::Sys;
+use super::types::WeatherResponse;
+
+impl WeatherResponse {
+    /// Whether the observation was made between sunrise and sunset.
+    ///
+    /// `None` when the API does not report sunrise and sunset (e.g. polar night or polar day).
+    pub fn is_daytime(&self) -> Option<bool> {
+        Some(self.sys.sunrise? <= self.dt && self.dt < self.sys.sunset?)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl WeatherResponse {
+    /// Time of data calculation in UTC, or `None` if `dt` is out of range.
+    pub fn observation_time(&self) -> Option<DateTime<Utc>> {
+        to_utc(self.dt)
+    }
+
+    /// Offset of the location's time zone, or `None` if `timezone` is out of range.
+    pub fn timezone_offset(&self) -> Option<FixedOffset> {
+        FixedOffset::east_opt(self.timezone)
+    }
+
+    /// Time of data calculation in the location's local time.
+    pub fn local_observation_time(&self) -> Option<DateTime<FixedOffset>> {
+        Some(self.observation_time()?.with_timezone(&self.timezone_offset()?))
+    }
+
+    /// Sunrise in the location's local time.
+    pub fn local_sunrise(&self) -> Option<DateTime<FixedOffset>> {
+        self.sys.local_sunrise(self.timezone_offset()?)
+    }
+
+    /// Sunset in the location's local time.
+    pub fn local_sunset(&self) -> Option<DateTime<FixedOffset>> {
+        self.sys.local_sunset(self.timezone_offset()?)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Sys {
+    /// Sunrise time in UTC, or `None` if `sunrise` is not reported or out of range.
+    pub fn sunrise_time(&self) -> Option<DateTime<Utc>> {
+        to_utc(self.sunrise?)
+    }
+
+    /// Sunset time in UTC, or `None` if `sunset` is not reported or out of range.
+    pub fn sunset_time(&self) -> Option<DateTime<Utc>> {
+        to_utc(self.sunset?)
+    }
+
+    /// Sunrise time at `offset` (usually `WeatherResponse::timezone_offset`).
+    pub fn local_sunrise(&self, offset: FixedOffset) -> Option<DateTime<FixedOffset>> {
+        Some(self.sunrise_time()?.with_timezone(&offset))
+    }
+
+    /// Sunset time at `offset` (usually `WeatherResponse::timezone_offset`).
+    pub fn local_sunset(&self, offset: FixedOffset) -> Option<DateTime<FixedOffset>> {
+        Some(self.sunset_time()?.with_timezone(&offset))
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn to_utc(timestamp: i64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(timestamp, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(dt: i64, timezone: i32, sunrise: Option<i64>, sunset: Option<i64>) -> WeatherResponse {
+        serde_json::from_value(serde_json::json!({
+            "coord": { "lon": -82.1993, "lat": 42.4421 },
+            "weather": [],
+            "base": "stations",
+            "main": {},
+            "wind": { "speed": 3.6 },
+            "clouds": { "all": 20 },
+            "dt": dt,
+            "sys": { "sunrise": sunrise, "sunset": sunset },
+            "timezone": timezone,
+            "id": 5920450,
+            "name": "Chatham-Kent",
+            "cod": 200
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn daytime_is_between_sunrise_and_sunset() {
+        assert_eq!(response(1752449935, -14400, Some(1752401008), Some(1752455116)).is_daytime(), Some(true));
+        assert_eq!(response(1752455116, -14400, Some(1752401008), Some(1752455116)).is_daytime(), Some(false));
+        assert_eq!(response(1752401008, -14400, Some(1752401008), Some(1752455116)).is_daytime(), Some(true));
+        assert_eq!(response(1752449935, -14400, None, None).is_daytime(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn local_times_west_of_greenwich() {
+        let weather = response(1752449935, -14400, Some(1752401008), Some(1752455116));
+
+        assert_eq!(weather.observation_time().unwrap().to_rfc3339(), "2025-07-13T23:38:55+00:00");
+        assert_eq!(weather.timezone_offset(), FixedOffset::west_opt(4 * 3600));
+        assert_eq!(weather.local_observation_time().unwrap().to_rfc3339(), "2025-07-13T19:38:55-04:00");
+        assert_eq!(weather.local_sunrise().unwrap().to_rfc3339(), "2025-07-13T06:03:28-04:00");
+        // The sunset falls on the next day in UTC but on the same local day.
+        assert_eq!(weather.sys.sunset_time().unwrap().to_rfc3339(), "2025-07-14T01:05:16+00:00");
+        assert_eq!(weather.local_sunset().unwrap().to_rfc3339(), "2025-07-13T21:05:16-04:00");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn local_times_east_of_greenwich() {
+        let weather = response(1726390800, 43200, Some(1726336503), Some(1726379692));
+
+        assert_eq!(weather.local_observation_time().unwrap().to_rfc3339(), "2024-09-15T21:00:00+12:00");
+        assert_eq!(weather.local_sunrise().unwrap().to_rfc3339(), "2024-09-15T05:55:03+12:00");
+        assert_eq!(weather.local_sunset().unwrap().to_rfc3339(), "2024-09-15T17:54:52+12:00");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn missing_or_out_of_range_values_are_none() {
+        let polar = response(1752449935, 0, None, None);
+        assert_eq!(polar.local_sunrise(), None);
+        assert_eq!(polar.sys.sunset_time(), None);
+        assert_eq!(polar.local_observation_time().unwrap().to_rfc3339(), "2025-07-13T23:38:55+00:00");
+
+        let bad_offset = response(1752449935, 86_400, Some(1752401008), Some(1752455116));
+        assert_eq!(bad_offset.timezone_offset(), None);
+        assert_eq!(bad_offset.local_observation_time(), None);
+        assert_eq!(bad_offset.local_sunrise(), None);
+
+        assert_eq!(response(i64::MAX, 0, None, None).observation_time(), None);
+    }
+}
@@ -1,44 +1,8 @@
 ﻿use serde::{Deserialize, Serialize};
-use wasm_bindgen::JsValue;
-use wasm_bindgen::prelude::wasm_bindgen;
-use crate::location::{Location, LocationClient};
-use crate::weather::WeatherClient;
+use crate::units::Units;
+use crate::weather::wind::{CompassLocale, CompassPoints};
 
-// region: Coord
-
-/// Represents geographic coordinates with longitude and latitude in decimal degrees.
-///
-/// Provides validation to ensure longitude is between -180 and 180 degrees,
-/// and latitude is between -90 and 90 degrees.
-///
-/// Sample JSON
-/// ```json
-/// "coord": {
-///     "lon": -82.1993,
-///     "lat": 42.4421
-/// }
-/// ```
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct Coord {
-    /// Longitude in decimal degrees
-    pub lon: f64,
-    /// Latitude in decimal degrees
-    pub lat: f64,
-}
-
-impl Coord {
-    pub fn new(lon: f64, lat: f64) -> Result<Self, String> {
-        if lon < -180.0 || lon > 180.0 {
-            return Err("Longitude must be between -180 and 180 degrees".to_string());
-        }
-        if lat < -90.0 || lat > 90.0 {
-            return Err("Latitude must be between -90 and 90 degrees".to_string());
-        }
-        Ok(Coord { lon, lat })
-    }
-}
-
-// endregion
+pub use crate::geo::{BoundingBox, Coord};
 
 // region Weather
 
@@ -116,19 +80,24 @@ pub struct Main {
     /// Maximum temperature at the moment (for large cities and urban areas)
     pub temp_max: Option<f64>,
     /// Atmospheric pressure on the sea level in hPa
+    #[serde(default, deserialize_with = "deserialize_rounded_option")]
     pub pressure: Option<i32>,
     /// Humidity percentage
+    #[serde(default, deserialize_with = "deserialize_rounded_option")]
     pub humidity: Option<i32>,
     /// Atmospheric pressure on the sea level in hPa (optional)
+    #[serde(default, deserialize_with = "deserialize_rounded_option")]
     pub sea_level: Option<i32>,
     /// Atmospheric pressure on the ground level in hPa (optional)
+    #[serde(default, deserialize_with = "deserialize_rounded_option")]
     pub grnd_level: Option<i32>,
 }
 
 impl Main {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(temp: Option<f64>, feels_like: Option<f64>, temp_min: Option<f64>, temp_max: Option<f64>, pressure: Option<i32>, humidity: Option<i32>, sea_level: Option<i32>, grnd_level: Option<i32>) -> Result<Self, String> {
         if let Some(humidity_value) = humidity {
-            if humidity_value < 0 || humidity_value > 100 {
+            if !(0..=100).contains(&humidity_value) {
                 return Err("Humidity must be between 0 and 100 percent".to_string());
             }
         }
@@ -144,7 +113,8 @@ impl Main {
 /// Represents meteorological wind data including speed, direction, and gusts.
 ///
 /// - `speed`: Wind speed with units depending on the API request (e.g., meters per second for metric or miles per hour for imperial).
-/// - `deg`: Wind direction in degrees, measured meteorologically.
+/// - `deg`: Wind direction in degrees, measured meteorologically. Omitted by the API in calm
+///   or variable conditions.
 /// - `gust`: Optional wind gust speed, same units as `speed`.
 ///
 /// Sample JSON
@@ -158,22 +128,25 @@ impl Main {
 pub struct Wind {
     /// Wind speed (units vary by API request: m/s for metric, mph for imperial)
     pub speed: f64,
-    /// Wind direction in degrees (meteorological)
-    pub deg: i32,
+    /// Wind direction in degrees (meteorological), if reported
+    #[serde(default, deserialize_with = "deserialize_rounded_option")]
+    pub deg: Option<i32>,
     /// Wind gust speed (optional, same units as speed)
     pub gust: Option<f64>,
 }
 
 impl Wind {
-    pub fn new(speed: f64, deg: i32, gust: Option<f64>) -> Result<Self, String> {
+    pub fn new(speed: f64, deg: Option<i32>, gust: Option<f64>) -> Result<Self, String> {
         // Validate speed minimum
         if speed < 0.0 {
             return Err("Wind speed must be non-negative".to_string());
         }
 
         // Validate degree range - FIXED to match schema
-        if deg < 0 || deg > 360 {
-            return Err("Wind direction must be between 0 and 360 degrees (inclusive)".to_string());
+        if let Some(deg) = deg {
+            if !(0..=360).contains(&deg) {
+                return Err("Wind direction must be between 0 and 360 degrees (inclusive)".to_string());
+            }
         }
 
         // Validate gust minimum if present
@@ -205,12 +178,13 @@ impl Wind {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Clouds {
     /// Cloudiness, %
+    #[serde(alias = "today")]
     pub all: i32,
 }
 
 impl Clouds {
     pub fn new(all: i32) -> Result<Self, String> {
-        if all < 0 || all > 100 {
+        if !(0..=100).contains(&all) {
             return Err("Clouds::all must be between 0 and 100 percent".to_string());
         }
 
@@ -234,6 +208,10 @@ impl Clouds {
 /// - `sunrise`: Sunrise time as a Unix timestamp (UTC).
 /// - `sunset`: Sunset time as a Unix timestamp (UTC).
 ///
+/// The API omits `type`, `id` and `country` for some coordinates, such as points over the
+/// ocean or readings from internal stations. During polar night or polar day there is no
+/// sunrise or sunset: the API then omits them or sends `0`, and both read as `None`.
+///
 /// Sample JSON
 /// ```json
 /// "sys": {
@@ -246,24 +224,28 @@ impl Clouds {
 /// ```
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Sys {
-    /// Internal parameter
+    /// Internal parameter (omitted for some coordinates, e.g. over oceans)
     #[serde(rename = "type")]
-    pub sys_type: i32,
-    /// Internal parameter
-    pub id: i32,
-    /// Country code (ISO 3166-1 alpha-2)
-    pub country: String,
-    /// Sunrise time, unix, UTC
-    pub sunrise: i64,
-    /// Sunset time, unix, UTC
-    pub sunset: i64,
+    pub sys_type: Option<i32>,
+    /// Internal parameter (omitted for some coordinates, e.g. over oceans)
+    pub id: Option<i32>,
+    /// Country code (ISO 3166-1 alpha-2), omitted outside of any country
+    pub country: Option<String>,
+    /// Sunrise time, unix, UTC (`None` when the sun does not rise or set that day)
+    #[serde(default, deserialize_with = "deserialize_timestamp_option")]
+    pub sunrise: Option<i64>,
+    /// Sunset time, unix, UTC (`None` when the sun does not rise or set that day)
+    #[serde(default, deserialize_with = "deserialize_timestamp_option")]
+    pub sunset: Option<i64>,
 }
 
 impl Sys {
-    pub fn new(sys_type: i32, id: i32, country: String, sunrise: i64, sunset: i64) -> Result<Self, String> {
+    pub fn new(sys_type: Option<i32>, id: Option<i32>, country: Option<String>, sunrise: Option<i64>, sunset: Option<i64>) -> Result<Self, String> {
         // Validate country code format (ISO 3166-1 alpha-2)
-        if !country.chars().all(|c| c.is_ascii_uppercase()) || country.len() != 2 {
-            return Err("Country code must be exactly 2 uppercase letters (ISO 3166-1 alpha-2)".to_string());
+        if let Some(country) = &country {
+            if !country.chars().all(|c| c.is_ascii_uppercase()) || country.len() != 2 {
+                return Err("Country code must be exactly 2 uppercase letters (ISO 3166-1 alpha-2)".to_string());
+            }
         }
 
         Ok(Sys {
@@ -292,7 +274,7 @@ impl Sys {
 /// - `weather`: List of weather condition descriptors (e.g., rain, clear sky).
 /// - `base`: Internal parameter indicating data source.
 /// - `main`: Main weather measurements such as temperature, pressure, and humidity.
-/// - `visibility`: Visibility distance in meters.
+/// - `visibility`: Visibility distance in meters, if reported.
 /// - `wind`: Wind speed and direction data.
 /// - `clouds`: Cloud coverage data.
 /// - `dt`: Timestamp of the weather data calculation in Unix UTC time.
@@ -360,8 +342,8 @@ pub struct WeatherResponse {
     pub base: String,
     /// Main weather measurements (temperature, pressure, humidity, etc.)
     pub main: Main,
-    /// Visibility information
-    pub visibility: i64,
+    /// Visibility in meters (omitted by some stations)
+    pub visibility: Option<i64>,
     /// Wind information
     pub wind: Wind,
     /// Cloud coverage information
@@ -381,12 +363,13 @@ pub struct WeatherResponse {
 }
 
 impl WeatherResponse {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         coord: Coord,
         weather: Vec<Weather>,
         base: String,
         main: Main,
-        visibility: i64,
+        visibility: Option<i64>,
         wind: Wind,
         clouds: Clouds,
         dt: i64,
@@ -396,7 +379,7 @@ impl WeatherResponse {
         name: String,
         cod: i64) -> Result<Self, String> {
         // Validate Visibility
-        if visibility < 0 {
+        if visibility.is_some_and(|visibility| visibility < 0) {
             return Err("Visibility must never be less than 0.".to_string());
         }
 
@@ -412,6 +395,14 @@ impl WeatherResponse {
 
         // Wind
         let wind_display = get_speed_display(self.wind.speed, &units);
+        let wind_direction = match (
+            self.wind.deg,
+            self.wind.compass_abbreviation(CompassPoints::Sixteen, &CompassLocale::english()),
+            self.wind.arrow(),
+        ) {
+            (Some(deg), Some(abbreviation), Some(arrow)) => format!(" at {}° {} {}", deg, abbreviation, arrow),
+            _ => String::new(),
+        };
 
         // Weather
         let mut weather_main = "";
@@ -427,7 +418,7 @@ impl WeatherResponse {
             r#"🌤️ Weather in {}
 📍 Coordinates: ({}, {})
 🌡️ Temperature: {}
-💨 Wind: {} at {}°
+💨 Wind: {}{}, {}
 ☁️ Clouds: {}%
 🌈 Conditions: {} ({})
    Icon: {}"#,
@@ -436,7 +427,8 @@ impl WeatherResponse {
             self.coord.lon,
             temp_display,
             wind_display,
-            self.wind.deg,
+            wind_direction,
+            self.wind.beaufort(Units::from_api_name(&units)),
             self.clouds.all,
             weather_main,
             weather_description,
@@ -447,11 +439,150 @@ impl WeatherResponse {
 
 // endregion
 
+// region: Precipitation
+
+/// Represents rain or snow volume for the last one and/or three hours, in mm.
+///
+/// Sample JSON
+/// ```json
+/// "rain": {
+///     "1h": 0.25
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Precipitation {
+    /// Volume for the last hour, mm
+    #[serde(rename = "1h")]
+    pub one_hour: Option<f64>,
+    /// Volume for the last three hours, mm
+    #[serde(rename = "3h")]
+    pub three_hours: Option<f64>,
+}
+
+// endregion
+
+// region: CityWeather
+
+/// Represents the country information attached to a city in area queries.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CitySys {
+    /// Country code (ISO 3166-1 alpha-2)
+    pub country: Option<String>,
+}
+
+/// Represents the current weather of one city returned by an area query
+/// (cities within a circle or cities within a bounding box).
+///
+/// The fields mirror [`WeatherResponse`], but the API omits `base`, `visibility`,
+/// `timezone`, sunrise and sunset for these items. Use `WeatherResponse::from` when a
+/// full `WeatherResponse` is needed; missing values are `None`, zero or empty.
+///
+/// Sample JSON
+/// ```json
+/// {
+///     "id": 5920450,
+///     "name": "Chatham-Kent",
+///     "coord": {
+///         "lat": 42.4421,
+///         "lon": -82.1993
+///     },
+///     "main": {
+///         "temp": 27.77,
+///         "feels_like": 29.32,
+///         "temp_min": 27.77,
+///         "temp_max": 29.1,
+///         "pressure": 1014,
+///         "humidity": 62
+///     },
+///     "dt": 1752449935,
+///     "wind": {
+///         "speed": 3.6,
+///         "deg": 220
+///     },
+///     "sys": {
+///         "country": "CA"
+///     },
+///     "rain": null,
+///     "snow": null,
+///     "clouds": {
+///         "all": 20
+///     },
+///     "weather": [
+///         {
+///             "id": 801,
+///             "main": "Clouds",
+///             "description": "few clouds",
+///             "icon": "02d"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CityWeather {
+    /// City ID
+    pub id: i64,
+    /// City name
+    pub name: String,
+    /// Geographic coordinates of the city
+    pub coord: Coord,
+    /// Main weather measurements (temperature, pressure, humidity, etc.)
+    pub main: Main,
+    /// Time of data calculation in Unix timestamp UTC
+    pub dt: i64,
+    /// Wind information
+    pub wind: Wind,
+    /// Country information (only returned by the cities-in-circle query)
+    pub sys: Option<CitySys>,
+    /// Rain volume (optional)
+    pub rain: Option<Precipitation>,
+    /// Snow volume (optional)
+    pub snow: Option<Precipitation>,
+    /// Cloud coverage information
+    pub clouds: Clouds,
+    /// Weather condition information (can be multiple conditions)
+    pub weather: Vec<Weather>,
+}
+
+impl From<CityWeather> for WeatherResponse {
+    fn from(city: CityWeather) -> Self {
+        WeatherResponse {
+            coord: city.coord,
+            weather: city.weather,
+            base: String::new(),
+            main: city.main,
+            visibility: None,
+            wind: city.wind,
+            clouds: city.clouds,
+            dt: city.dt,
+            sys: Sys {
+                sys_type: None,
+                id: None,
+                country: city.sys.and_then(|sys| sys.country),
+                sunrise: None,
+                sunset: None,
+            },
+            timezone: 0,
+            id: city.id,
+            name: city.name,
+            cod: 200,
+        }
+    }
+}
+
+/// Represents the envelope of an area query response.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CityWeatherList {
+    /// Weather for every city found in the area
+    pub list: Vec<CityWeather>,
+}
+
+// endregion
+
 fn get_temperature_display(temp: &f64, units: &str) -> String {
     match units {
         "metric" => format!("{:.1}°C", temp),
         "imperial" => format!("{:.1}°F", temp),
-        "standard" | _ => format!("{:.1}°K", temp),
+        _ => format!("{:.1}°K", temp),
     }
 }
 
@@ -459,101 +590,24 @@ fn get_speed_display(speed: f64, units: &str) -> String {
     match units {
         "metric" => format!("{:.1} m/s", speed),
         "imperial" => format!("{:.1} mph", speed),
-        "standard" | _ => format!("{:.1} m/s", speed),
+        _ => format!("{:.1} m/s", speed),
     }
 }
 
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = console)]
-    fn log(s: &str);
-}
-
-macro_rules! console_log {
-    ($($t:tt)*) => (log(&format_args!($($t)*).to_string()))
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct WeatherRequestWasm {
-    pub zip: String,
-    pub country: String,
-    pub units: String,
-    pub api_key: String,
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct WeatherResponseWasm {
-    pub location: Location,
-    pub weather: String,
-    pub error: Option<String>,
+/// Reads an optional unix timestamp, treating `0` as missing.
+pub(crate) fn deserialize_timestamp_option<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<i64>::deserialize(deserializer)?;
+    Ok(value.filter(|&timestamp| timestamp != 0))
 }
 
-#[wasm_bindgen]
-pub async fn get_weather_data(request_json: &str) -> Result<String, JsValue> {
-    console_log!("WASM function called with: {}", request_json);
-
-    let request: WeatherRequestWasm = serde_json::from_str(request_json)
-        .map_err(|e| {
-            console_log!("JSON parse error: {}", e);
-            JsValue::from_str(&format!("Invalid request: {}", e))
-        })?;
-
-    console_log!("Parsed request: {:?}", request);
-
-    match fetch_weather_internal(request).await {
-        Ok(response) => {
-            console_log!("Weather fetch successful");
-            serde_json::to_string(&response)
-                .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
-        }
-        Err(e) => {
-            console_log!("Weather fetch error: {}", e);
-            let error_response = WeatherResponseWasm {
-                location: Location {
-                    zip: String::new(),
-                    name: String::new(),
-                    lat: 0.0,
-                    lon: 0.0,
-                    country: String::new(),
-                },
-                weather: String::new(),
-                error: Some(e),
-            };
-            serde_json::to_string(&error_response)
-                .map_err(|e| JsValue::from_str(&format!("Error serialization failed: {}", e)))
-        }
-    }
+/// Accepts integer fields that some endpoints send as floating point (e.g. `"pressure": 961.02`).
+pub(crate) fn deserialize_rounded_option<'de, D>(deserializer: D) -> Result<Option<i32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<f64>::deserialize(deserializer)?;
+    Ok(value.map(|v| v.round() as i32))
 }
-
-async fn fetch_weather_internal(request: WeatherRequestWasm) -> Result<WeatherResponseWasm, String> {
-    console_log!("Creating location client");
-    console_log!("Fetching location");
-
-    let location = LocationClient::new(
-        request.zip.clone(),
-        request.country.clone(),
-        request.api_key.clone(), )
-        .get_location()
-        .await
-        .map_err(|e| format!("Location error: {}", e))?;
-
-    console_log!("Location found: {:?}", location);
-    console_log!("Fetching weather");
-
-    let weather_response = WeatherClient::new(
-        location.clone(),
-        request.units.clone(),
-        request.api_key.clone(), )
-        .get_current_weather()
-        .await
-        .map_err(|e| format!("Weather error: {}", e))?;
-
-    console_log!("Weather fetch complete");
-
-    Ok(WeatherResponseWasm {
-        location,
-        weather: serde_json::to_string(&weather_response)
-            .map_err(|e| format!("Weather serialization error: {}", e))?,
-        error: None,
-    })
-}
\ No newline at end of file
@@ -0,0 +1,280 @@
+//! Wind direction and strength analysis: compass points, Beaufort scale and arrow glyphs.
+//!
+//! The free functions work on raw degrees and speeds so they can be used with any wind
+//! data (current weather, hourly or daily forecasts); [`Wind`] gets convenience methods.
+
+use std::fmt;
+
+use super::types::Wind;
+use crate::units::Units;
+
+// region: Compass
+
+/// Represents the resolution of a compass rose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompassPoints {
+    /// N, NE, E, ... (45° sectors)
+    Eight,
+    /// N, NNE, NE, ... (22.5° sectors)
+    Sixteen,
+    /// N, NbE, NNE, ... (11.25° sectors)
+    ThirtyTwo,
+}
+
+impl CompassPoints {
+    fn count(&self) -> usize {
+        match self {
+            CompassPoints::Eight => 8,
+            CompassPoints::Sixteen => 16,
+            CompassPoints::ThirtyTwo => 32,
+        }
+    }
+}
+
+/// Names of the 32 compass points, starting at north and going clockwise.
+///
+/// The 8- and 16-point roses use every fourth and every second entry respectively, so a
+/// translation only has to provide the 32-point names.
+#[derive(Debug, Clone)]
+pub struct CompassLocale {
+    names: Vec<String>,
+    abbreviations: Vec<String>,
+}
+
+impl CompassLocale {
+    pub fn new(names: Vec<String>, abbreviations: Vec<String>) -> Result<Self, String> {
+        if names.len() != 32 || abbreviations.len() != 32 {
+            return Err("Compass locale must provide exactly 32 names and 32 abbreviations".to_string());
+        }
+
+        Ok(CompassLocale { names, abbreviations })
+    }
+
+    /// English compass point names.
+    pub fn english() -> Self {
+        const NAMES: [&str; 32] = [
+            "North", "North by east", "North-northeast", "Northeast by north",
+            "Northeast", "Northeast by east", "East-northeast", "East by north",
+            "East", "East by south", "East-southeast", "Southeast by east",
+            "Southeast", "Southeast by south", "South-southeast", "South by east",
+            "South", "South by west", "South-southwest", "Southwest by south",
+            "Southwest", "Southwest by west", "West-southwest", "West by south",
+            "West", "West by north", "West-northwest", "Northwest by west",
+            "Northwest", "Northwest by north", "North-northwest", "North by west",
+        ];
+        const ABBREVIATIONS: [&str; 32] = [
+            "N", "NbE", "NNE", "NEbN", "NE", "NEbE", "ENE", "EbN",
+            "E", "EbS", "ESE", "SEbE", "SE", "SEbS", "SSE", "SbE",
+            "S", "SbW", "SSW", "SWbS", "SW", "SWbW", "WSW", "WbS",
+            "W", "WbN", "WNW", "NWbW", "NW", "NWbN", "NNW", "NbW",
+        ];
+
+        CompassLocale {
+            names: NAMES.iter().map(|s| s.to_string()).collect(),
+            abbreviations: ABBREVIATIONS.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Full name of the compass point closest to `deg`.
+    pub fn name(&self, deg: f64, points: CompassPoints) -> &str {
+        &self.names[compass_index(deg, points)]
+    }
+
+    /// Abbreviation of the compass point closest to `deg`.
+    pub fn abbreviation(&self, deg: f64, points: CompassPoints) -> &str {
+        &self.abbreviations[compass_index(deg, points)]
+    }
+}
+
+impl Default for CompassLocale {
+    fn default() -> Self {
+        CompassLocale::english()
+    }
+}
+
+/// Index into the 32-point table of the point closest to `deg` on a rose with `points` points.
+fn compass_index(deg: f64, points: CompassPoints) -> usize {
+    let count = points.count();
+    let sector = (deg.rem_euclid(360.0) / (360.0 / count as f64)).round() as usize % count;
+    sector * (32 / count)
+}
+
+// endregion
+
+// region: Beaufort
+
+/// Represents a force on the Beaufort wind scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Beaufort {
+    /// Beaufort number, from 0 (calm) to 12 (hurricane force)
+    pub force: u8,
+}
+
+impl Beaufort {
+    /// Upper bounds (exclusive) of forces 0 to 11 in m/s.
+    const LIMITS: [f64; 12] = [0.5, 1.6, 3.4, 5.5, 8.0, 10.8, 13.9, 17.2, 20.8, 24.5, 28.5, 32.7];
+
+    /// Classifies a wind speed given in the request `units` (mph for `Imperial`, otherwise m/s).
+    pub fn from_speed(speed: f64, units: Units) -> Self {
+        let meters_per_second = match units {
+            Units::Imperial => speed * 0.447_04,
+            Units::Standard | Units::Metric => speed,
+        };
+
+        let force = Self::LIMITS.iter().take_while(|&&limit| meters_per_second >= limit).count();
+        Beaufort { force: force as u8 }
+    }
+
+    /// English description of the force (e.g. "Gentle breeze").
+    pub fn description(&self) -> &'static str {
+        match self.force {
+            0 => "Calm",
+            1 => "Light air",
+            2 => "Light breeze",
+            3 => "Gentle breeze",
+            4 => "Moderate breeze",
+            5 => "Fresh breeze",
+            6 => "Strong breeze",
+            7 => "Near gale",
+            8 => "Gale",
+            9 => "Strong gale",
+            10 => "Storm",
+            11 => "Violent storm",
+            _ => "Hurricane force",
+        }
+    }
+}
+
+impl fmt::Display for Beaufort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (Beaufort {})", self.description(), self.force)
+    }
+}
+
+// endregion
+
+// region: Arrow
+
+/// Arrow pointing where the wind blows to, for a meteorological direction `deg`
+/// (the direction the wind comes from). A northerly wind gives `↓`.
+pub fn wind_arrow(deg: f64) -> char {
+    const ARROWS: [char; 8] = ['↓', '↙', '←', '↖', '↑', '↗', '→', '↘'];
+    ARROWS[compass_index(deg, CompassPoints::Eight) / 4]
+}
+
+// endregion
+
+impl Wind {
+    /// Full name of the direction the wind comes from, if the direction is reported.
+    pub fn compass_name<'a>(&self, points: CompassPoints, locale: &'a CompassLocale) -> Option<&'a str> {
+        Some(locale.name(self.deg? as f64, points))
+    }
+
+    /// Abbreviated name of the direction the wind comes from, if the direction is reported.
+    pub fn compass_abbreviation<'a>(&self, points: CompassPoints, locale: &'a CompassLocale) -> Option<&'a str> {
+        Some(locale.abbreviation(self.deg? as f64, points))
+    }
+
+    /// Beaufort force of the sustained wind speed.
+    pub fn beaufort(&self, units: Units) -> Beaufort {
+        Beaufort::from_speed(self.speed, units)
+    }
+
+    /// Beaufort force of the gusts, if reported.
+    pub fn gust_beaufort(&self, units: Units) -> Option<Beaufort> {
+        self.gust.map(|gust| Beaufort::from_speed(gust, units))
+    }
+
+    /// Arrow pointing where the wind blows to, if the direction is reported.
+    pub fn arrow(&self) -> Option<char> {
+        Some(wind_arrow(self.deg? as f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn abbreviation(deg: f64, points: CompassPoints) -> String {
+        CompassLocale::english().abbreviation(deg, points).to_string()
+    }
+
+    #[test]
+    fn compass_index_rounds_half_sectors_clockwise() {
+        assert_eq!(compass_index(11.24, CompassPoints::Sixteen), 0);
+        assert_eq!(compass_index(11.25, CompassPoints::Sixteen), 2);
+        assert_eq!(compass_index(348.74, CompassPoints::Sixteen), 30);
+        assert_eq!(compass_index(348.75, CompassPoints::Sixteen), 0);
+        assert_eq!(compass_index(11.25, CompassPoints::ThirtyTwo), 1);
+        assert_eq!(compass_index(22.5, CompassPoints::Eight), 4);
+    }
+
+    #[test]
+    fn compass_index_wraps_full_turns_and_negative_degrees() {
+        assert_eq!(compass_index(360.0, CompassPoints::ThirtyTwo), 0);
+        assert_eq!(compass_index(720.0 + 90.0, CompassPoints::Eight), 8);
+        assert_eq!(compass_index(-11.25, CompassPoints::Sixteen), 0);
+        assert_eq!(compass_index(-22.5, CompassPoints::Sixteen), 30);
+        assert_eq!(compass_index(-90.0, CompassPoints::Eight), 24);
+    }
+
+    #[test]
+    fn names_for_each_rose() {
+        let locale = CompassLocale::english();
+        assert_eq!(locale.name(196.0, CompassPoints::Eight), "South");
+        assert_eq!(locale.name(196.0, CompassPoints::Sixteen), "South-southwest");
+        assert_eq!(locale.name(196.0, CompassPoints::ThirtyTwo), "South by west");
+
+        assert_eq!(abbreviation(135.0, CompassPoints::Eight), "SE");
+        assert_eq!(abbreviation(292.5, CompassPoints::Sixteen), "WNW");
+        assert_eq!(abbreviation(33.75, CompassPoints::ThirtyTwo), "NEbN");
+    }
+
+    #[test]
+    fn locale_requires_32_names_and_abbreviations() {
+        let names = |count: usize| (0..count).map(|i| i.to_string()).collect::<Vec<_>>();
+        assert!(CompassLocale::new(names(32), names(32)).is_ok());
+        assert!(CompassLocale::new(names(16), names(32)).is_err());
+        assert!(CompassLocale::new(names(32), names(33)).is_err());
+        assert_eq!(CompassLocale::new(names(32), names(32)).unwrap().name(90.0, CompassPoints::Eight), "8");
+    }
+
+    #[test]
+    fn beaufort_limits_are_exclusive_upper_bounds() {
+        assert_eq!(Beaufort::from_speed(0.0, Units::Metric).force, 0);
+        for (force, &limit) in Beaufort::LIMITS.iter().enumerate() {
+            assert_eq!(Beaufort::from_speed(limit - 0.01, Units::Metric).force as usize, force, "below {}", limit);
+            assert_eq!(Beaufort::from_speed(limit, Units::Metric).force as usize, force + 1, "at {}", limit);
+        }
+        assert_eq!(Beaufort::from_speed(60.0, Units::Metric).description(), "Hurricane force");
+    }
+
+    #[test]
+    fn beaufort_converts_imperial_speeds() {
+        assert_eq!(Beaufort::from_speed(1.1, Units::Imperial).force, 0);
+        assert_eq!(Beaufort::from_speed(1.2, Units::Imperial).force, 1);
+        assert_eq!(Beaufort::from_speed(10.0, Units::Imperial).force, 3);
+        assert_eq!(Beaufort::from_speed(10.0, Units::Standard).force, 5);
+    }
+
+    #[test]
+    fn arrows_point_downwind() {
+        assert_eq!(wind_arrow(0.0), '↓');
+        assert_eq!(wind_arrow(45.0), '↙');
+        assert_eq!(wind_arrow(90.0), '←');
+        assert_eq!(wind_arrow(180.0), '↑');
+        assert_eq!(wind_arrow(270.0), '→');
+        assert_eq!(wind_arrow(315.0), '↘');
+        assert_eq!(wind_arrow(-45.0), '↘');
+        assert_eq!(wind_arrow(359.0), '↓');
+    }
+
+    #[test]
+    fn wind_helpers_need_a_direction() {
+        let wind = Wind::new(3.6, None, Some(9.0)).unwrap();
+        assert_eq!(wind.arrow(), None);
+        assert_eq!(wind.compass_name(CompassPoints::Eight, &CompassLocale::english()), None);
+        assert_eq!(wind.beaufort(Units::Metric).force, 3);
+        assert_eq!(wind.gust_beaufort(Units::Metric).map(|b| b.force), Some(5));
+    }
+}
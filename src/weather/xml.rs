@@ -0,0 +1,266 @@
+//! Deserialization of the `mode=xml` current weather payload.
+//!
+//! The XML document returned by OpenWeatherMap carries the same information as the JSON
+//! response, but in a different shape (attributes instead of fields, ISO 8601 timestamps
+//! instead of Unix time). This module parses the XML into private mirror structs and
+//! converts them into the regular [`WeatherResponse`] so callers only deal with one type.
+//!
+//! Sample XML
+//! ```xml
+//! <current>
+//!     <city id="5920450" name="Chatham-Kent">
+//!         <coord lon="-82.1993" lat="42.4421"/>
+//!         <country>CA</country>
+//!         <timezone>-14400</timezone>
+//!         <sun rise="2025-07-13T10:03:28" set="2025-07-14T01:05:16"/>
+//!     </city>
+//!     <temperature value="27.77" min="27.77" max="29.1" unit="celsius"/>
+//!     <feels_like value="29.32" unit="celsius"/>
+//!     <humidity value="62" unit="%"/>
+//!     <pressure value="1014" unit="hPa"/>
+//!     <wind>
+//!         <speed value="3.6" unit="m/s" name="Gentle Breeze"/>
+//!         <gusts/>
+//!         <direction value="220" code="SW" name="Southwest"/>
+//!     </wind>
+//!     <clouds value="20" name="few clouds"/>
+//!     <visibility value="10000"/>
+//!     <precipitation mode="no"/>
+//!     <weather number="801" value="few clouds" icon="02d"/>
+//!     <lastupdate value="2025-07-13T23:38:55"/>
+//! </current>
+//! ```
+
+use quick_xml::DeError;
+use serde::de::Error as _;
+use serde::Deserialize;
+
+use super::types::{Clouds, Coord, Main, Sys, Weather, WeatherResponse, Wind};
+use crate::calendar::checked_days_from_civil;
+
+#[derive(Debug, Deserialize)]
+struct XmlCurrent {
+    city: XmlCity,
+    temperature: XmlTemperature,
+    feels_like: Option<XmlValue<f64>>,
+    humidity: Option<XmlValue<i32>>,
+    pressure: Option<XmlValue<i32>>,
+    wind: XmlWind,
+    clouds: XmlValue<i32>,
+    visibility: Option<XmlValue<i64>>,
+    weather: XmlWeather,
+    lastupdate: XmlValue<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlCity {
+    #[serde(rename = "@id")]
+    id: i64,
+    #[serde(rename = "@name")]
+    name: String,
+    coord: XmlCoord,
+    country: Option<String>,
+    timezone: i32,
+    sun: Option<XmlSun>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlCoord {
+    #[serde(rename = "@lon")]
+    lon: f64,
+    #[serde(rename = "@lat")]
+    lat: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlSun {
+    #[serde(rename = "@rise")]
+    rise: Option<String>,
+    #[serde(rename = "@set")]
+    set: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlTemperature {
+    #[serde(rename = "@value")]
+    value: Option<f64>,
+    #[serde(rename = "@min")]
+    min: Option<f64>,
+    #[serde(rename = "@max")]
+    max: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlValue<T> {
+    #[serde(rename = "@value")]
+    value: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlWind {
+    speed: XmlValue<f64>,
+    gusts: Option<XmlGusts>,
+    direction: Option<XmlDirection>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlDirection {
+    #[serde(rename = "@value")]
+    value: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlGusts {
+    #[serde(rename = "@value")]
+    value: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlWeather {
+    #[serde(rename = "@number")]
+    number: i32,
+    #[serde(rename = "@value")]
+    value: String,
+    #[serde(rename = "@icon")]
+    icon: String,
+}
+
+/// Parses a `mode=xml` current weather document into a [`WeatherResponse`].
+///
+/// Fields that only exist in the JSON payload are filled in with neutral values:
+/// `base` is `"xml"`, `cod` is `200` and the internal `sys.type`/`sys.id` are `None`.
+/// The weather group (`Weather::main`) is derived from the condition ID. A missing or empty
+/// wind direction reads as `None`, and so do sunrise and sunset when the document has none
+/// (polar night and polar day).
+///
+/// Malformed documents and values the response types reject are both reported as
+/// [`DeError`].
+pub fn from_xml_str(xml: &str) -> Result<WeatherResponse, Box<dyn std::error::Error>> {
+    let current: XmlCurrent = quick_xml::de::from_str(xml)?;
+    Ok(weather_response(current).map_err(DeError::custom)?)
+}
+
+fn weather_response(current: XmlCurrent) -> Result<WeatherResponse, String> {
+    Ok(WeatherResponse {
+        coord: Coord::new(current.city.coord.lon, current.city.coord.lat)?,
+        weather: vec![Weather {
+            id: current.weather.number,
+            main: weather_group(current.weather.number).to_string(),
+            description: current.weather.value,
+            icon: current.weather.icon,
+        }],
+        base: "xml".to_string(),
+        main: Main::new(
+            current.temperature.value,
+            current.feels_like.map(|v| v.value),
+            current.temperature.min,
+            current.temperature.max,
+            current.pressure.map(|v| v.value),
+            current.humidity.map(|v| v.value),
+            None,
+            None,
+        )?,
+        visibility: current.visibility.map(|v| v.value),
+        wind: Wind::new(
+            current.wind.speed.value,
+            current.wind.direction.and_then(|v| v.value).map(|deg| deg.round() as i32),
+            current.wind.gusts.and_then(|g| g.value),
+        )?,
+        clouds: Clouds::new(current.clouds.value)?,
+        dt: parse_utc_timestamp(&current.lastupdate.value)?,
+        sys: Sys::new(
+            None,
+            None,
+            current.city.country.filter(|country| !country.is_empty()),
+            parse_sun_timestamp(current.city.sun.as_ref().and_then(|sun| sun.rise.as_deref()))?,
+            parse_sun_timestamp(current.city.sun.as_ref().and_then(|sun| sun.set.as_deref()))?,
+        )?,
+        timezone: current.city.timezone,
+        id: current.city.id,
+        name: current.city.name,
+        cod: 200,
+    })
+}
+
+/// Maps a weather condition ID onto the group name used by the JSON API.
+pub(crate) fn weather_group(id: i32) -> &'static str {
+    match id {
+        200..=299 => "Thunderstorm",
+        300..=399 => "Drizzle",
+        500..=599 => "Rain",
+        600..=699 => "Snow",
+        701 => "Mist",
+        711 => "Smoke",
+        721 => "Haze",
+        731 | 761 => "Dust",
+        741 => "Fog",
+        751 => "Sand",
+        762 => "Ash",
+        771 => "Squall",
+        781 => "Tornado",
+        800 => "Clear",
+        801..=899 => "Clouds",
+        _ => "",
+    }
+}
+
+/// Parses a sunrise or sunset attribute. Like the JSON payload, the Unix epoch stands for
+/// "no sunrise/sunset that day".
+pub(crate) fn parse_sun_timestamp(value: Option<&str>) -> Result<Option<i64>, String> {
+    match value.filter(|value| !value.is_empty()) {
+        Some(value) => Ok(Some(parse_utc_timestamp(value)?).filter(|&timestamp| timestamp != 0)),
+        None => Ok(None),
+    }
+}
+
+/// Parses an ISO 8601 timestamp without offset (`YYYY-MM-DDTHH:MM:SS`, always UTC in the
+/// XML payload) into Unix seconds, rejecting dates and times that do not exist.
+pub(crate) fn parse_utc_timestamp(value: &str) -> Result<i64, String> {
+    let invalid = || format!("Invalid timestamp: {}", value);
+    let (date, time) = value.split_once('T').ok_or_else(invalid)?;
+
+    let date: Vec<i64> = date.split('-').map(str::parse).collect::<Result<_, _>>().map_err(|_| invalid())?;
+    let time: Vec<i64> = time.split(':').map(str::parse).collect::<Result<_, _>>().map_err(|_| invalid())?;
+    if date.len() != 3 || time.len() != 3 {
+        return Err(invalid());
+    }
+
+    let (hours, minutes, seconds) = (time[0], time[1], time[2]);
+    if !(0..24).contains(&hours) || !(0..60).contains(&minutes) || !(0..60).contains(&seconds) {
+        return Err(invalid());
+    }
+
+    let days = checked_days_from_civil(date[0], date[1], date[2]).map_err(|_| invalid())?;
+    Ok(days * 86_400 + hours * 3_600 + minutes * 60 + seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_utc_timestamps() {
+        assert_eq!(parse_utc_timestamp("1970-01-01T00:00:00"), Ok(0));
+        assert_eq!(parse_utc_timestamp("2025-07-13T10:03:28"), Ok(1752401008));
+        assert_eq!(parse_utc_timestamp("2024-02-29T23:59:59"), Ok(1709251199));
+    }
+
+    #[test]
+    fn rejects_dates_and_times_that_do_not_exist() {
+        assert!(parse_utc_timestamp("2025-02-30T25:99:99").is_err());
+        assert!(parse_utc_timestamp("2025-02-30T12:00:00").is_err());
+        assert!(parse_utc_timestamp("2025-13-01T12:00:00").is_err());
+        assert!(parse_utc_timestamp("2025-07-13T24:00:00").is_err());
+        assert!(parse_utc_timestamp("2025-07-13T12:60:00").is_err());
+        assert!(parse_utc_timestamp("2025-07-13T12:00:60").is_err());
+        assert!(parse_utc_timestamp("2025-07-13 12:00:00").is_err());
+    }
+
+    #[test]
+    fn sun_timestamps_treat_the_epoch_and_empty_values_as_none() {
+        assert_eq!(parse_sun_timestamp(None), Ok(None));
+        assert_eq!(parse_sun_timestamp(Some("")), Ok(None));
+        assert_eq!(parse_sun_timestamp(Some("1970-01-01T00:00:00")), Ok(None));
+        assert_eq!(parse_sun_timestamp(Some("2025-07-13T10:03:28")), Ok(Some(1752401008)));
+    }
+}
@@ -0,0 +1,49 @@
+//! Offline sun times must agree with the sunrise and sunset reported by the API.
+
+use openweathermap_lib::astronomy::SunTimes;
+use openweathermap_lib::weather::WeatherResponse;
+
+mod common;
+
+/// The API and the offline calculation use slightly different models; two minutes covers the
+/// difference at mid latitudes.
+const TOLERANCE_SECONDS: i64 = 120;
+
+fn fixture(name: &str) -> WeatherResponse {
+    common::json_fixture("weather", name)
+}
+
+fn assert_close(name: &str, event: &str, computed: Option<i64>, reported: Option<i64>) {
+    match (computed, reported) {
+        (Some(computed), Some(reported)) => assert!(
+            (computed - reported).abs() <= TOLERANCE_SECONDS,
+            "{} {}: computed {} reported {} ({} s apart)",
+            name,
+            event,
+            computed,
+            reported,
+            computed - reported
+        ),
+        _ => assert_eq!(computed, reported, "{} {}", name, event),
+    }
+}
+
+#[test]
+fn sun_times_match_reported_sys_values() {
+    for name in ["london_solstice", "new_york", "chatham_kent", "calm", "ocean", "date_line", "polar_night", "polar_day"] {
+        let weather = fixture(name);
+        let sun = SunTimes::for_timestamp(&weather.coord, weather.dt, weather.timezone);
+
+        assert_close(name, "sunrise", sun.sunrise, weather.sys.sunrise);
+        assert_close(name, "sunset", sun.sunset, weather.sys.sunset);
+    }
+}
+
+#[test]
+fn polar_day_and_night_lengths() {
+    let night = fixture("polar_night");
+    let day = fixture("polar_day");
+
+    assert_eq!(SunTimes::for_timestamp(&night.coord, night.dt, night.timezone).day_length, 0);
+    assert_eq!(SunTimes::for_timestamp(&day.coord, day.dt, day.timezone).day_length, 86_400);
+}
@@ -0,0 +1,15 @@
+//! Fixture loading shared by the integration tests.
+
+use serde::de::DeserializeOwned;
+
+/// Reads `tests/fixtures/<path>`, panicking with the path if it cannot be read.
+pub fn read_fixture(path: &str) -> String {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), path);
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+/// Parses `tests/fixtures/<dir>/<name>.json`, panicking with the path if it does not parse.
+pub fn json_fixture<T: DeserializeOwned>(dir: &str, name: &str) -> T {
+    let path = format!("{}/{}.json", dir, name);
+    serde_json::from_str(&read_fixture(&path)).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
@@ -0,0 +1,77 @@
+//! Deserialization of the 5 day / 3-hour, hourly and daily forecast payloads.
+
+use openweathermap_lib::forecast::{DailyForecast, Forecast, HourlyForecast};
+use serde::de::DeserializeOwned;
+
+mod common;
+
+fn fixture<T: DeserializeOwned>(name: &str) -> T {
+    common::json_fixture("forecast", name)
+}
+
+#[test]
+fn forecast_steps_are_three_hours_apart() {
+    let forecast: Forecast = fixture("chatham_kent");
+
+    assert_eq!(forecast.cnt, 3);
+    assert_eq!(forecast.list.len(), 3);
+    assert!(forecast.list.windows(2).all(|pair| pair[1].dt - pair[0].dt == 3 * 60 * 60));
+    assert_eq!(forecast.city.name, "Chatham-Kent");
+    assert_eq!(forecast.city.sunrise, Some(1752401008));
+}
+
+#[test]
+fn forecast_step_fields() {
+    let forecast: Forecast = fixture("chatham_kent");
+    let first = &forecast.list[0];
+
+    assert_eq!(first.main.temp, Some(27.5));
+    assert_eq!(first.wind.deg, Some(221));
+    assert_eq!(first.pop, Some(0.32));
+    assert_eq!(first.rain.as_ref().and_then(|rain| rain.three_hours), Some(0.41));
+    assert_eq!(first.sys.as_ref().map(|sys| sys.pod.as_str()), Some("n"));
+    assert_eq!(first.dt_txt.as_deref(), Some("2025-07-14 00:00:00"));
+    assert!(forecast.list[1].rain.is_none());
+}
+
+#[test]
+fn hourly_forecast_steps_are_one_hour_apart() {
+    let forecast: HourlyForecast = fixture("chatham_kent_hourly");
+
+    assert_eq!(forecast.cnt, 2);
+    assert!(forecast.list.windows(2).all(|pair| pair[1].dt - pair[0].dt == 60 * 60));
+    assert_eq!(forecast.city.country.as_deref(), Some("CA"));
+
+    let first = &forecast.list[0];
+    assert_eq!(first.rain.as_ref().and_then(|rain| rain.one_hour), Some(0.21));
+    assert_eq!(first.wind.deg, Some(221));
+
+    let calm = &forecast.list[1];
+    assert_eq!(calm.main.pressure, Some(1015));
+    assert_eq!(calm.wind.deg, None);
+    assert!(calm.rain.is_none());
+}
+
+#[test]
+fn daily_forecast_during_polar_day() {
+    let forecast: DailyForecast = fixture("longyearbyen_daily");
+
+    assert_eq!(forecast.cnt, 2);
+    assert_eq!(forecast.city.country.as_deref(), Some("SJ"));
+    assert_eq!(forecast.city.sunrise, None);
+
+    let first = &forecast.list[0];
+    assert_eq!(first.sunrise, None);
+    assert_eq!(first.sunset, None);
+    assert_eq!(first.pressure, Some(1009));
+    assert_eq!(first.humidity, Some(81));
+    assert_eq!(first.deg, Some(167));
+    assert_eq!(first.rain, Some(1.3));
+    assert_eq!(first.temp.max, 8.1);
+
+    let second = &forecast.list[1];
+    assert_eq!(second.pressure, None);
+    assert_eq!(second.humidity, None);
+    assert_eq!(second.deg, None);
+    assert_eq!(second.rain, None);
+}
@@ -0,0 +1,77 @@
+//! Deserialization of real-world current weather payloads that stress the optional fields:
+//! points over the ocean, polar night and polar day, the antimeridian and calm wind.
+
+use openweathermap_lib::weather::WeatherResponse;
+
+mod common;
+
+fn fixture(name: &str) -> WeatherResponse {
+    common::json_fixture("weather", name)
+}
+
+#[test]
+fn ocean_point_has_no_country_station_or_visibility() {
+    let weather = fixture("ocean");
+
+    assert_eq!(weather.name, "");
+    assert_eq!(weather.sys.sys_type, None);
+    assert_eq!(weather.sys.id, None);
+    assert_eq!(weather.sys.country, None);
+    assert_eq!(weather.visibility, None);
+    assert_eq!(weather.sys.sunrise, Some(1710948271));
+    assert_eq!(weather.sys.sunset, Some(1710991871));
+    assert_eq!(weather.is_daytime(), Some(true));
+}
+
+#[test]
+fn polar_night_zero_sun_times_read_as_none() {
+    let weather = fixture("polar_night");
+
+    assert_eq!(weather.sys.country.as_deref(), Some("SJ"));
+    assert_eq!(weather.sys.sunrise, None);
+    assert_eq!(weather.sys.sunset, None);
+    assert_eq!(weather.is_daytime(), None);
+}
+
+#[test]
+fn polar_day_missing_sun_times_read_as_none() {
+    let weather = fixture("polar_day");
+
+    assert_eq!(weather.sys.sunrise, None);
+    assert_eq!(weather.sys.sunset, None);
+    assert_eq!(weather.is_daytime(), None);
+}
+
+#[test]
+fn date_line_keeps_longitude_and_local_day() {
+    let weather = fixture("date_line");
+
+    assert_eq!(weather.coord.lon, -179.99);
+    assert_eq!(weather.timezone, 43_200);
+    // Sunrise falls on the previous UTC day but on the same local day as the observation.
+    let local_day = |timestamp: i64| (timestamp + weather.timezone as i64).div_euclid(86_400);
+    assert_eq!(local_day(weather.sys.sunrise.unwrap()), local_day(weather.dt));
+    assert_eq!(local_day(weather.sys.sunset.unwrap()), local_day(weather.dt));
+    assert_eq!(weather.is_daytime(), Some(false));
+}
+
+#[test]
+fn calm_wind_has_no_direction() {
+    let weather = fixture("calm");
+
+    assert_eq!(weather.wind.speed, 0.0);
+    assert_eq!(weather.wind.deg, None);
+    assert_eq!(weather.wind.arrow(), None);
+    assert!(weather.detailed_display("metric".to_string()).contains("Wind: 0.0 m/s, Calm"));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_sun_times_are_none_without_sunrise() {
+    let weather = fixture("polar_night");
+
+    assert_eq!(weather.sys.sunrise_time(), None);
+    assert_eq!(weather.local_sunrise(), None);
+    assert_eq!(weather.local_sunset(), None);
+    assert!(weather.local_observation_time().is_some());
+}
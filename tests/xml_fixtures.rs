@@ -0,0 +1,141 @@
+//! `mode=xml` documents must parse into the same types as the JSON payload returned for
+//! the same observation or forecast.
+
+#![cfg(feature = "xml")]
+
+use openweathermap_lib::forecast::{self, Forecast};
+use openweathermap_lib::weather::xml::from_xml_str;
+use openweathermap_lib::weather::WeatherResponse;
+
+mod common;
+
+fn read(name: &str) -> String {
+    common::read_fixture(&format!("weather/{}", name))
+}
+
+fn parse_pair(name: &str) -> (WeatherResponse, WeatherResponse) {
+    let xml = from_xml_str(&read(&format!("{}.xml", name))).unwrap();
+    (xml, common::json_fixture("weather", name))
+}
+
+/// Compares every field the XML document carries. `base`, `sys.type`, `sys.id` and the
+/// sea/ground level pressures only exist in JSON.
+fn assert_same_observation(xml: &WeatherResponse, json: &WeatherResponse) {
+    assert_eq!(xml.coord, json.coord);
+    assert_eq!(xml.id, json.id);
+    assert_eq!(xml.name, json.name);
+    assert_eq!(xml.timezone, json.timezone);
+    assert_eq!(xml.dt, json.dt);
+
+    assert_eq!(xml.main.temp, json.main.temp);
+    assert_eq!(xml.main.feels_like, json.main.feels_like);
+    assert_eq!(xml.main.temp_min, json.main.temp_min);
+    assert_eq!(xml.main.temp_max, json.main.temp_max);
+    assert_eq!(xml.main.pressure, json.main.pressure);
+    assert_eq!(xml.main.humidity, json.main.humidity);
+
+    assert_eq!(xml.wind.speed, json.wind.speed);
+    assert_eq!(xml.wind.deg, json.wind.deg);
+    assert_eq!(xml.wind.gust, json.wind.gust);
+    assert_eq!(xml.clouds.all, json.clouds.all);
+    assert_eq!(xml.visibility, json.visibility);
+
+    assert_eq!(xml.weather.len(), 1);
+    assert_eq!(xml.weather[0].id, json.weather[0].id);
+    assert_eq!(xml.weather[0].main, json.weather[0].main);
+    assert_eq!(xml.weather[0].description, json.weather[0].description);
+    assert_eq!(xml.weather[0].icon, json.weather[0].icon);
+
+    assert_eq!(xml.sys.country, json.sys.country);
+    assert_eq!(xml.sys.sunrise, json.sys.sunrise);
+    assert_eq!(xml.sys.sunset, json.sys.sunset);
+}
+
+#[test]
+fn xml_matches_json() {
+    let (xml, json) = parse_pair("chatham_kent");
+    assert_same_observation(&xml, &json);
+    assert_eq!(xml.base, "xml");
+    assert_eq!(xml.sys.sys_type, None);
+}
+
+#[test]
+fn empty_wind_direction_is_none() {
+    let (xml, json) = parse_pair("calm");
+    assert_same_observation(&xml, &json);
+    assert_eq!(xml.wind.deg, None);
+}
+
+#[test]
+fn epoch_sun_times_are_none() {
+    let (xml, json) = parse_pair("polar_night");
+    assert_same_observation(&xml, &json);
+    assert_eq!(xml.sys.sunrise, None);
+}
+
+#[test]
+fn missing_sun_times_are_none() {
+    let (xml, json) = parse_pair("polar_day");
+    assert_same_observation(&xml, &json);
+    assert_eq!(xml.sys.sunset, None);
+}
+
+#[test]
+fn missing_sun_element_is_none() {
+    let xml = read("calm.xml").replace(r#"<sun rise="2024-12-09T07:04:18" set="2024-12-09T15:41:11"></sun>"#, "");
+    let weather = from_xml_str(&xml).unwrap();
+    assert_eq!(weather.sys.sunrise, None);
+    assert_eq!(weather.sys.sunset, None);
+}
+
+#[test]
+fn impossible_timestamp_is_an_error() {
+    let xml = read("calm.xml").replace("2024-12-09T07:20:00", "2025-02-30T25:99:99");
+    assert!(from_xml_str(&xml).is_err());
+}
+
+#[test]
+fn malformed_document_is_an_error() {
+    assert!(from_xml_str("<current><city/></current>").is_err());
+}
+
+#[test]
+fn forecast_xml_matches_json() {
+    let xml = forecast::xml::from_xml_str(&common::read_fixture("forecast/chatham_kent.xml")).unwrap();
+    let json: Forecast = common::json_fixture("forecast", "chatham_kent");
+
+    assert_eq!(xml.cnt, json.cnt);
+    assert_eq!(xml.city.id, json.city.id);
+    assert_eq!(xml.city.name, json.city.name);
+    assert_eq!(xml.city.coord, json.city.coord);
+    assert_eq!(xml.city.country, json.city.country);
+    assert_eq!(xml.city.timezone, json.city.timezone);
+    assert_eq!(xml.city.sunrise, json.city.sunrise);
+    assert_eq!(xml.city.sunset, json.city.sunset);
+    assert_eq!(xml.city.population, None);
+
+    assert_eq!(xml.list.len(), json.list.len());
+    for (xml, json) in xml.list.iter().zip(&json.list) {
+        assert_eq!(xml.dt, json.dt);
+        assert_eq!(xml.dt_txt, json.dt_txt);
+        assert_eq!(xml.main.temp, json.main.temp);
+        assert_eq!(xml.main.feels_like, json.main.feels_like);
+        assert_eq!(xml.main.temp_min, json.main.temp_min);
+        assert_eq!(xml.main.temp_max, json.main.temp_max);
+        assert_eq!(xml.main.pressure, json.main.pressure);
+        assert_eq!(xml.main.humidity, json.main.humidity);
+        assert_eq!(xml.wind.speed, json.wind.speed);
+        assert_eq!(xml.wind.deg, json.wind.deg);
+        assert_eq!(xml.wind.gust, json.wind.gust);
+        assert_eq!(xml.clouds.all, json.clouds.all);
+        assert_eq!(xml.visibility, json.visibility);
+        assert_eq!(xml.pop, json.pop);
+        assert_eq!(xml.rain.as_ref().and_then(|r| r.three_hours), json.rain.as_ref().and_then(|r| r.three_hours));
+        assert_eq!(xml.snow.is_some(), json.snow.is_some());
+        assert_eq!(xml.sys.as_ref().map(|s| &s.pod), json.sys.as_ref().map(|s| &s.pod));
+        assert_eq!(xml.weather[0].id, json.weather[0].id);
+        assert_eq!(xml.weather[0].main, json.weather[0].main);
+        assert_eq!(xml.weather[0].description, json.weather[0].description);
+        assert_eq!(xml.weather[0].icon, json.weather[0].icon);
+    }
+}